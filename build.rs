@@ -1,31 +1,100 @@
+#[cfg(feature = "bindgen")]
+mod build_bindings;
+mod build_config;
 mod build_freertos;
 use anyhow::*;
 use std::env;
+use std::path::Path;
 
 // See: https://doc.rust-lang.org/cargo/reference/build-scripts.html
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=build_config.rs");
+    println!("cargo:rerun-if-changed=build_bindings.rs");
+    println!("cargo:rustc-check-cfg=cfg(freertos_default_config)");
 
     let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
     let freertos_kernel_dir = format!("{}/dep/FreeRTOS-Kernel", manifest_dir);
     let xtensa_dir = format!("{}/dep/xtensa", manifest_dir);
 
+    let chip = chip()?;
+
+    let config = build_config::Config::from_env(chip)?;
+    config.validate()?;
+    // Included by `FreeRTOSConfig.h`.
+    let config_include_dir = config.write(Path::new(&out_dir))?;
+
     let mut b = build_freertos::Builder::new();
 
     b.freertos_shim("port");
     b.freertos(&freertos_kernel_dir);
-    b.freertos_config("port"); // Location of `FreeRTOSConfig.h`
-    b.freertos_port("port/esp32".into());
-    b.get_cc()
-        .include(&format!("{}/include", xtensa_dir))
-        .include(&format!("{}/esp32/include", xtensa_dir))
-        .flag("-mlongcalls");
+    b.get_cc().include(&config_include_dir);
+    if chip == "host" {
+        // The POSIX port is detected from the target.
+        b.freertos_config("port/host");
+    } else {
+        b.freertos_config("port"); // Location of `FreeRTOSConfig.h`
+        b.freertos_port("port/esp32".into());
+        b.get_cc()
+            .include(&format!("{}/include", xtensa_dir))
+            .include(&format!("{}/{}/include", xtensa_dir, chip))
+            .flag("-mlongcalls");
+    }
+
+    if env::var_os("CARGO_FEATURE_HEAP_4").is_some() {
+        b.heap("heap_4.c".into());
+    } else if env::var_os("CARGO_FEATURE_HEAP_5").is_some() {
+        b.heap("heap_5.c".into());
+    }
 
     b.compile()
         .or_else(|e| Err(anyhow!("FreeRTOS compilation failed: {}", e.to_string())))?;
 
-    println!("cargo:rustc-link-search={}/esp32", xtensa_dir);
-    println!("cargo:rustc-link-lib=xt_hal");
+    #[cfg(feature = "bindgen")]
+    {
+        build_bindings::generate(
+            chip,
+            &b.clang_args(),
+            &config.generated_regex(),
+            Path::new(&out_dir),
+        )?;
+        // The pre-generated bindings can only be compared to these for the same config.
+        if chip != "host" && config == build_config::Config::defaults(chip) {
+            println!("cargo:rustc-cfg=freertos_default_config");
+        }
+    }
+
+    if chip == "host" {
+        // The tasks of the POSIX port are threads.
+        println!("cargo:rustc-link-lib=pthread");
+    } else {
+        println!("cargo:rustc-link-search={}/{}", xtensa_dir, chip);
+        println!("cargo:rustc-link-lib=xt_hal");
+    }
 
     Ok(())
 }
+
+/// The chip selected by the `esp32`, `esp32s2` or `esp32s3` feature, or `host` for the
+/// `host-sim` feature.
+fn chip() -> Result<&'static str> {
+    let chips: Vec<_> = ["esp32", "esp32s2", "esp32s3", "host-sim"]
+        .iter()
+        .copied()
+        .filter(|chip| {
+            let feature = chip.to_uppercase().replace('-', "_");
+            env::var_os(format!("CARGO_FEATURE_{}", feature)).is_some()
+        })
+        .collect();
+    match chips[..] {
+        ["host-sim"] => Ok("host"),
+        [chip] => Ok(chip),
+        [] => bail!(
+            "one of the `esp32`, `esp32s2`, `esp32s3` and `host-sim` features must be enabled"
+        ),
+        _ => bail!(
+            "only one of the `esp32`, `esp32s2`, `esp32s3` and `host-sim` features can be enabled"
+        ),
+    }
+}
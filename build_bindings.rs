@@ -0,0 +1,41 @@
+//! Generate the bindings for the configured kernel with the `bindgen` feature, with the
+//! same options as `generate_bindings.sh`.
+
+use anyhow::{anyhow, Result};
+use std::path::Path;
+
+/// Generate `bindings.rs` in `out_dir` from `wrapper.h`, `clang_args` are the include
+/// paths and defines the kernel is compiled with. The items matching `generated_config`
+/// (see `Config::generated_regex`) are emitted by `config.rs` instead.
+///
+/// The sysroot of the xtensa toolchain can be passed in `BINDGEN_EXTRA_CLANG_ARGS`.
+pub fn generate(
+    chip: &str,
+    clang_args: &[String],
+    generated_config: &str,
+    out_dir: &Path,
+) -> Result<()> {
+    println!("cargo:rerun-if-changed=wrapper.h");
+
+    let mut builder = bindgen::Builder::default()
+        .header("wrapper.h")
+        .use_core()
+        .size_t_is_usize(true)
+        .ctypes_prefix("chlorine")
+        .generate_inline_functions(true)
+        .blocklist_item(generated_config)
+        // Defined in `glue.rs` with `use-rust-alloc` only, re-exported from there.
+        .blocklist_function("pvPortMallocAligned")
+        .clang_args(clang_args)
+        .parse_callbacks(Box::new(bindgen::CargoCallbacks));
+    // The host is the default target of bindgen.
+    if chip != "host" {
+        builder = builder.clang_arg(format!("--target=xtensa-{}-none-elf", chip));
+    }
+    let bindings = builder
+        .generate()
+        .map_err(|()| anyhow!("generating the bindings for the {} failed", chip))?;
+    bindings.write_to_file(out_dir.join("bindings.rs"))?;
+
+    Ok(())
+}
@@ -0,0 +1,301 @@
+//! The kernel configuration: read from the cargo features and `FREERTOS_*` environment
+//! variables, validated and written to a header included by `port/FreeRTOSConfig.h` and
+//! to Rust constants included by `src/config.rs`.
+
+use anyhow::{anyhow, bail, Result};
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// `configTOTAL_HEAP_SIZE` of `heap_4`, unless overridden by `FREERTOS_HEAP_SIZE`.
+const DEFAULT_HEAP_SIZE: u32 = 128 * 1024;
+/// The task name length `StaticTask_t` in the pre-generated bindings was generated with.
+const BINDINGS_MAX_TASK_NAME_LEN: u32 = 16;
+/// The notification slots per task `StaticTask_t` in the pre-generated bindings has.
+const BINDINGS_TASK_NOTIFICATION_ARRAY_ENTRIES: u32 = 1;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub num_cores: u32,
+    pub tick_rate_hz: u32,
+    pub max_priorities: u32,
+    /// In words, like all stack sizes except `isr_stack_size`.
+    pub minimal_stack_size: u32,
+    pub idle_task_stack_size: u32,
+    /// In bytes.
+    pub isr_stack_size: u32,
+    pub max_task_name_len: u32,
+    pub task_notification_array_entries: u32,
+    pub queue_registry_size: u32,
+    pub timer_task_priority: u32,
+    pub timer_queue_length: u32,
+    pub timer_task_stack_depth: u32,
+    /// `configTOTAL_HEAP_SIZE`, only set with the kernel's `heap_4`.
+    pub total_heap_size: Option<u32>,
+    /// `configCHECK_FOR_STACK_OVERFLOW` method.
+    pub check_for_stack_overflow: u32,
+    pub support_static_allocation: bool,
+    /// Compile `configASSERT` out (`assert-disable`). What happens after a failed
+    /// assert otherwise is decided on the Rust side, in `vPortPanic`.
+    pub disable_asserts: bool,
+    /// The allocator functions are implemented in Rust (`use-rust-alloc`), including
+    /// `pvPortMallocAligned`.
+    pub rust_alloc: bool,
+}
+
+impl Config {
+    /// The configuration used without any `FREERTOS_*` variables or configuration features,
+    /// which the pre-generated bindings were generated with.
+    pub fn defaults(chip: &str) -> Config {
+        Config {
+            num_cores: match chip {
+                "esp32s2" | "host" => 1,
+                _ => 2,
+            },
+            tick_rate_hz: 100,
+            max_priorities: 25,
+            minimal_stack_size: 768,
+            idle_task_stack_size: 768,
+            isr_stack_size: 1536,
+            max_task_name_len: BINDINGS_MAX_TASK_NAME_LEN,
+            task_notification_array_entries: BINDINGS_TASK_NOTIFICATION_ARRAY_ENTRIES,
+            queue_registry_size: 0,
+            timer_task_priority: 1,
+            timer_queue_length: 10,
+            timer_task_stack_depth: 1024,
+            total_heap_size: None,
+            // Method 2 checks a canary at the end of the stack.
+            check_for_stack_overflow: 2,
+            support_static_allocation: false,
+            disable_asserts: false,
+            // The default feature.
+            rust_alloc: true,
+        }
+    }
+
+    /// Read the configuration for `chip` from the cargo features and the environment.
+    pub fn from_env(chip: &str) -> Result<Config> {
+        let defaults = Config::defaults(chip);
+
+        let heap_size = number("FREERTOS_HEAP_SIZE")?;
+        let total_heap_size = if feature("heap-4") {
+            Some(heap_size.unwrap_or(DEFAULT_HEAP_SIZE))
+        } else if heap_size.is_some() {
+            bail!("`FREERTOS_HEAP_SIZE` is only used by the `heap-4` feature");
+        } else {
+            None
+        };
+
+        // Method 1 checks the stack pointer on context switches.
+        let check_for_stack_overflow = if feature("stack-overflow-ptrval") {
+            1
+        } else {
+            defaults.check_for_stack_overflow
+        };
+
+        Ok(Config {
+            tick_rate_hz: number("FREERTOS_TICK_RATE_HZ")?.unwrap_or(defaults.tick_rate_hz),
+            max_priorities: number("FREERTOS_MAX_PRIORITIES")?.unwrap_or(defaults.max_priorities),
+            minimal_stack_size: number("FREERTOS_MINIMAL_STACK_SIZE")?
+                .unwrap_or(defaults.minimal_stack_size),
+            idle_task_stack_size: number("FREERTOS_IDLE_TASK_STACK_SIZE")?
+                .unwrap_or(defaults.idle_task_stack_size),
+            isr_stack_size: number("FREERTOS_ISR_STACK_SIZE")?.unwrap_or(defaults.isr_stack_size),
+            max_task_name_len: number("FREERTOS_MAX_TASK_NAME_LEN")?
+                .unwrap_or(defaults.max_task_name_len),
+            task_notification_array_entries: number("FREERTOS_TASK_NOTIFICATION_ARRAY_ENTRIES")?
+                .unwrap_or(defaults.task_notification_array_entries),
+            queue_registry_size: number("FREERTOS_QUEUE_REGISTRY_SIZE")?
+                .unwrap_or(defaults.queue_registry_size),
+            timer_task_priority: number("FREERTOS_TIMER_TASK_PRIORITY")?
+                .unwrap_or(defaults.timer_task_priority),
+            timer_queue_length: number("FREERTOS_TIMER_QUEUE_LENGTH")?
+                .unwrap_or(defaults.timer_queue_length),
+            timer_task_stack_depth: number("FREERTOS_TIMER_TASK_STACK_DEPTH")?
+                .unwrap_or(defaults.timer_task_stack_depth),
+            total_heap_size,
+            check_for_stack_overflow,
+            support_static_allocation: feature("static-alloc"),
+            disable_asserts: feature("assert-disable"),
+            rust_alloc: feature("use-rust-alloc"),
+            ..defaults
+        })
+    }
+
+    /// Reject values the kernel or the Rust side can't work with.
+    pub fn validate(&self) -> Result<()> {
+        if !(1..=1000).contains(&self.tick_rate_hz) {
+            bail!("`FREERTOS_TICK_RATE_HZ` must be between 1 and 1000");
+        }
+        if self.max_priorities < 2 {
+            bail!("`FREERTOS_MAX_PRIORITIES` must be at least 2");
+        }
+        if self.timer_task_priority >= self.max_priorities {
+            bail!("`FREERTOS_TIMER_TASK_PRIORITY` must be below `FREERTOS_MAX_PRIORITIES`");
+        }
+        if self.minimal_stack_size == 0 {
+            bail!("`FREERTOS_MINIMAL_STACK_SIZE` must not be 0");
+        }
+        if self.idle_task_stack_size < self.minimal_stack_size {
+            bail!("`FREERTOS_IDLE_TASK_STACK_SIZE` must be at least `FREERTOS_MINIMAL_STACK_SIZE`");
+        }
+        if self.timer_task_stack_depth < self.minimal_stack_size {
+            bail!(
+                "`FREERTOS_TIMER_TASK_STACK_DEPTH` must be at least `FREERTOS_MINIMAL_STACK_SIZE`"
+            );
+        }
+        // The interrupt stack of core `n` starts at `(n + 1) * configISR_STACK_SIZE`.
+        if self.isr_stack_size == 0 || self.isr_stack_size & 15 != 0 {
+            bail!("`FREERTOS_ISR_STACK_SIZE` must be a non-zero multiple of 16");
+        }
+        if self.max_task_name_len == 0 {
+            bail!("`FREERTOS_MAX_TASK_NAME_LEN` must not be 0");
+        }
+        // The `bindgen` feature generates `StaticTask_t` with the configured length.
+        if self.support_static_allocation
+            && !feature("bindgen")
+            && self.max_task_name_len != BINDINGS_MAX_TASK_NAME_LEN
+        {
+            bail!(
+                "`FREERTOS_MAX_TASK_NAME_LEN` must be {} with the `static-alloc` feature, \
+                 `StaticTask_t` in the pre-generated bindings has room for {} characters",
+                BINDINGS_MAX_TASK_NAME_LEN,
+                BINDINGS_MAX_TASK_NAME_LEN
+            );
+        }
+        if self.task_notification_array_entries == 0 {
+            bail!("`FREERTOS_TASK_NOTIFICATION_ARRAY_ENTRIES` must not be 0");
+        }
+        if self.support_static_allocation
+            && !feature("bindgen")
+            && self.task_notification_array_entries != BINDINGS_TASK_NOTIFICATION_ARRAY_ENTRIES
+        {
+            bail!(
+                "`FREERTOS_TASK_NOTIFICATION_ARRAY_ENTRIES` must be {} with the `static-alloc` \
+                 feature, `StaticTask_t` in the pre-generated bindings has {} slot",
+                BINDINGS_TASK_NOTIFICATION_ARRAY_ENTRIES,
+                BINDINGS_TASK_NOTIFICATION_ARRAY_ENTRIES
+            );
+        }
+        if self.timer_queue_length == 0 {
+            bail!("`FREERTOS_TIMER_QUEUE_LENGTH` must not be 0");
+        }
+        if self.total_heap_size == Some(0) {
+            bail!("`FREERTOS_HEAP_SIZE` must not be 0");
+        }
+        Ok(())
+    }
+
+    /// The numeric `config*` macros, which are also emitted as Rust constants and left
+    /// out of the bindings. `None` values aren't defined.
+    fn values(&self) -> Vec<(&'static str, Option<u32>)> {
+        let mut values: Vec<_> = vec![
+            ("configNUM_CORES", self.num_cores),
+            ("configTICK_RATE_HZ", self.tick_rate_hz),
+            ("configMAX_PRIORITIES", self.max_priorities),
+            ("configMINIMAL_STACK_SIZE", self.minimal_stack_size),
+            ("configIDLE_TASK_STACK_SIZE", self.idle_task_stack_size),
+            ("configISR_STACK_SIZE", self.isr_stack_size),
+            ("configMAX_TASK_NAME_LEN", self.max_task_name_len),
+            (
+                "configTASK_NOTIFICATION_ARRAY_ENTRIES",
+                self.task_notification_array_entries,
+            ),
+            ("configQUEUE_REGISTRY_SIZE", self.queue_registry_size),
+            ("configTIMER_TASK_PRIORITY", self.timer_task_priority),
+            ("configTIMER_QUEUE_LENGTH", self.timer_queue_length),
+            ("configTIMER_TASK_STACK_DEPTH", self.timer_task_stack_depth),
+            // `uxTaskGetSystemState` is used by `backtrace::dump_all_tasks`.
+            ("configUSE_TRACE_FACILITY", 1),
+            (
+                "configCHECK_FOR_STACK_OVERFLOW",
+                self.check_for_stack_overflow,
+            ),
+            (
+                "configSUPPORT_STATIC_ALLOCATION",
+                self.support_static_allocation as u32,
+            ),
+        ]
+        .into_iter()
+        .map(|(name, value)| (name, Some(value)))
+        .collect();
+        values.push(("configTOTAL_HEAP_SIZE", self.total_heap_size));
+        values
+    }
+
+    fn header(&self) -> String {
+        let mut h = String::new();
+        writeln!(h, "/* Generated by build.rs, see `build_config.rs`. */").unwrap();
+        writeln!(h, "#ifndef FREERTOS_CONFIG_GENERATED_H").unwrap();
+        writeln!(h, "#define FREERTOS_CONFIG_GENERATED_H").unwrap();
+        writeln!(h).unwrap();
+        for (name, value) in self.values() {
+            if let Some(value) = value {
+                writeln!(h, "#define {} {}", name, value).unwrap();
+            }
+        }
+        if self.disable_asserts {
+            writeln!(h, "#define CONFIG_FREERTOS_ASSERT_DISABLE 1").unwrap();
+        }
+        if self.rust_alloc {
+            writeln!(h, "#define CONFIG_FREERTOS_RUST_ALLOC 1").unwrap();
+        }
+        writeln!(h).unwrap();
+        writeln!(h, "#endif /* FREERTOS_CONFIG_GENERATED_H */").unwrap();
+        h
+    }
+
+    fn rust_consts(&self) -> String {
+        let mut r = String::new();
+        writeln!(r, "// Generated by build.rs, see `build_config.rs`.").unwrap();
+        for (name, value) in self.values() {
+            if let Some(value) = value {
+                writeln!(r, "pub const {}: u32 = {};", name, value).unwrap();
+            }
+        }
+        r
+    }
+
+    /// The regex of the macros in [`Config::values`], which the bindings leave out.
+    pub fn generated_regex(&self) -> String {
+        let names: Vec<_> = self.values().into_iter().map(|(name, _)| name).collect();
+        // bindgen anchors the regex with `^` and `$` without a group.
+        format!("({})", names.join("|"))
+    }
+
+    /// Write `include/freertos_config.h` and `config.rs` to `out_dir` and return the
+    /// directory to add to the include path.
+    ///
+    /// `include/freertos_config.regex` holds [`Config::generated_regex`] for
+    /// `generate_bindings.sh`.
+    pub fn write(&self, out_dir: &Path) -> Result<PathBuf> {
+        let include_dir = out_dir.join("include");
+        fs::create_dir_all(&include_dir)?;
+        fs::write(include_dir.join("freertos_config.h"), self.header())?;
+        fs::write(
+            include_dir.join("freertos_config.regex"),
+            self.generated_regex(),
+        )?;
+        fs::write(out_dir.join("config.rs"), self.rust_consts())?;
+        Ok(include_dir)
+    }
+}
+
+fn feature(name: &str) -> bool {
+    let var = format!("CARGO_FEATURE_{}", name.to_uppercase().replace('-', "_"));
+    env::var_os(var).is_some()
+}
+
+/// Read the number in the environment variable `var`, if it is set.
+fn number(var: &str) -> Result<Option<u32>> {
+    println!("cargo:rerun-if-env-changed={}", var);
+    match env::var(var) {
+        Ok(value) => value
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|_| anyhow!("`{}` must be a number, not `{}`", var, value)),
+        Err(_) => Ok(None),
+    }
+}
@@ -107,7 +107,6 @@ impl Builder {
     /// Set the heap_?.c file to use from the "/portable/MemMang/" folder.
     /// heap_1.c ... heap_5.c (Default: heap_4.c)
     /// see also: https://www.freertos.org/a00111.html
-    #[allow(dead_code)]
     pub fn heap(&mut self, file_name: String) {
         self.heap_c = Some(file_name);
     }
@@ -147,7 +146,7 @@ impl Builder {
             target_env.as_str(),
         ) {
             (_, "x86_64", "windows", _) => "MSVC-MingW",
-            (_, "x86_64", "linux", "gnu") => "GCC/Linux",
+            (_, _, "linux", "gnu") => "ThirdParty/GCC/Posix",
             ("thumbv7m-none-eabi", _, _, _) => "GCC/ARM_CM3",
             // TODO We should support feature "trustzone"
             ("thumbv8m.main-none-eabi", _, _, _) => "GCC/ARM_CM33_NTZ/non_secure",
@@ -227,19 +226,42 @@ impl Builder {
         Ok(())
     }
 
-    pub fn compile(&self) -> Result<(), Error> {
+    /// The cc::Build with the FreeRTOS include paths added.
+    fn build(&self) -> Build {
         let mut b = self.cc.clone();
+        // FreeRTOS header files
+        b.include(&self.freertos_include_dir());
+        // FreeRTOS port header files (e.g. portmacro.h)
+        b.include(cargo_emit(&self.get_freertos_port_dir()));
+        b.include(cargo_emit(&self.freertos_config_dir.clone()));
+        b
+    }
 
+    /// The include paths and defines the sources are compiled with, as clang arguments.
+    pub fn clang_args(&self) -> Vec<String> {
+        let compiler = self.build().get_compiler();
+        let mut args = compiler
+            .args()
+            .iter()
+            .map(|arg| arg.to_string_lossy().into_owned());
+        let mut clang_args = Vec::new();
+        while let Some(arg) = args.next() {
+            if arg == "-I" {
+                clang_args.extend(args.next().map(|dir| format!("-I{}", dir)));
+            } else if arg.starts_with("-I") || arg.starts_with("-D") {
+                clang_args.push(arg);
+            }
+        }
+        clang_args
+    }
+
+    pub fn compile(&self) -> Result<(), Error> {
         let path_error = self.verify_paths();
         if path_error.is_err() {
             return path_error;
         }
 
-        // FreeRTOS header files
-        b.include(&self.freertos_include_dir());
-        // FreeRTOS port header files (e.g. portmacro.h)
-        b.include(cargo_emit(&self.get_freertos_port_dir()));
-        b.include(cargo_emit(&self.freertos_config_dir.clone()));
+        let mut b = self.build();
         if let Some(heap_c) = self.heap_c_file() {
             b.file(heap_c);
         }
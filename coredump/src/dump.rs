@@ -0,0 +1,213 @@
+//! Parser of the core dump format, see `src/coredump.rs` of `freertos-esp32-sys`.
+
+use std::convert::TryInto;
+
+use anyhow::{bail, Result};
+
+/// The first bytes of a core dump.
+pub const MAGIC: [u8; 4] = *b"FRCD";
+/// The supported version of the format.
+pub const VERSION: u16 = 1;
+
+/// The number of registers in a task record.
+pub const REGISTER_COUNT: usize = 24;
+pub const PC: usize = 0;
+pub const PS: usize = 1;
+/// `A0`, `A1` (the stack pointer) etc. follow at `A0 + n`.
+pub const A0: usize = 2;
+pub const SAR: usize = 18;
+pub const LBEG: usize = 21;
+pub const LEND: usize = 22;
+pub const LCOUNT: usize = 23;
+
+#[derive(Debug)]
+pub struct CoreDump {
+    /// The core that wrote the dump.
+    pub core_id: u32,
+    /// The number of tasks, more than `tasks` if the dump is truncated.
+    pub total_tasks: u32,
+    pub heap: HeapStats,
+    /// The task that wrote the dump comes first.
+    pub tasks: Vec<Task>,
+}
+
+#[derive(Debug, Default)]
+pub struct HeapStats {
+    pub size: u32,
+    pub used: u32,
+    pub peak: u32,
+    pub free: u32,
+    pub min_ever_free: u32,
+    pub allocations: u32,
+    pub frees: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegisterSource {
+    None,
+    Current,
+    Solicited,
+    Exception,
+}
+
+#[derive(Debug)]
+pub struct Task {
+    pub handle: u32,
+    pub name: String,
+    pub state: u32,
+    pub priority: u32,
+    pub source: RegisterSource,
+    pub stack_base: u32,
+    pub registers: [u32; REGISTER_COUNT],
+    pub tcb: Vec<u8>,
+    pub stack_addr: u32,
+    pub stack: Vec<u8>,
+}
+
+impl Task {
+    pub fn state_name(&self) -> &'static str {
+        match self.state {
+            0 => "running",
+            1 => "ready",
+            2 => "blocked",
+            3 => "suspended",
+            4 => "deleted",
+            _ => "invalid",
+        }
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.0.len() < len {
+            bail!("the core dump is truncated");
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_le_bytes(self.bytes(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+}
+
+pub fn parse(data: &[u8]) -> Result<CoreDump> {
+    let mut r = Reader(data);
+    if r.bytes(4)? != MAGIC {
+        bail!("not a core dump (wrong magic)");
+    }
+    let version = r.u16()?;
+    if version != VERSION {
+        bail!("unsupported core dump version {}", version);
+    }
+    let task_count = r.u16()?;
+    let core_id = r.u32()?;
+    let total_tasks = r.u32()?;
+    let heap = HeapStats {
+        size: r.u32()?,
+        used: r.u32()?,
+        peak: r.u32()?,
+        free: r.u32()?,
+        min_ever_free: r.u32()?,
+        allocations: r.u32()?,
+        frees: r.u32()?,
+    };
+
+    let mut tasks = Vec::with_capacity(task_count as usize);
+    for _ in 0..task_count {
+        let handle = r.u32()?;
+        let name = r.bytes(16)?;
+        let name = &name[..name.iter().position(|&b| b == 0).unwrap_or(name.len())];
+        let state = r.u32()?;
+        let priority = r.u32()?;
+        let source = match r.u32()? {
+            0 => RegisterSource::None,
+            1 => RegisterSource::Current,
+            2 => RegisterSource::Solicited,
+            3 => RegisterSource::Exception,
+            source => bail!("invalid register source {}", source),
+        };
+        let stack_base = r.u32()?;
+        let mut registers = [0; REGISTER_COUNT];
+        for register in registers.iter_mut() {
+            *register = r.u32()?;
+        }
+        let tcb_len = r.u32()? as usize;
+        let tcb = r.bytes(tcb_len)?.to_vec();
+        let stack_addr = r.u32()?;
+        let stack_len = r.u32()? as usize;
+        let stack = r.bytes(stack_len)?.to_vec();
+
+        tasks.push(Task {
+            handle,
+            name: String::from_utf8_lossy(name).into_owned(),
+            state,
+            priority,
+            source,
+            stack_base,
+            registers,
+            tcb,
+            stack_addr,
+            stack,
+        });
+    }
+
+    Ok(CoreDump {
+        core_id,
+        total_tasks,
+        heap,
+        tasks,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, RegisterSource, MAGIC, REGISTER_COUNT, VERSION};
+
+    #[test]
+    fn parses_a_dump() {
+        let mut data = MAGIC.to_vec();
+        data.extend(&VERSION.to_le_bytes());
+        data.extend(&1u16.to_le_bytes());
+        for word in [1u32, 40, 1000, 100, 200, 900, 800, 5, 4].iter() {
+            data.extend(&word.to_le_bytes());
+        }
+        data.extend(&0x3ffb_1000u32.to_le_bytes());
+        data.extend(b"main\0\0\0\0\0\0\0\0\0\0\0\0");
+        for word in [0u32, 5, 2, 0x3ffb_2000].iter() {
+            data.extend(&word.to_le_bytes());
+        }
+        for register in 0..REGISTER_COUNT as u32 {
+            data.extend(&register.to_le_bytes());
+        }
+        data.extend(&4u32.to_le_bytes());
+        data.extend(&[1, 2, 3, 4]);
+        data.extend(&0x3ffb_2f00u32.to_le_bytes());
+        data.extend(&8u32.to_le_bytes());
+        data.extend(&[0; 8]);
+
+        let dump = parse(&data).unwrap();
+        assert_eq!(dump.core_id, 1);
+        assert_eq!(dump.total_tasks, 40);
+        assert_eq!(dump.heap.size, 1000);
+        assert_eq!(dump.heap.frees, 4);
+        assert_eq!(dump.tasks.len(), 1);
+
+        let task = &dump.tasks[0];
+        assert_eq!(task.name, "main");
+        assert_eq!(task.source, RegisterSource::Solicited);
+        assert_eq!(task.registers[23], 23);
+        assert_eq!(task.tcb, [1, 2, 3, 4]);
+        assert_eq!(task.stack_addr, 0x3ffb_2f00);
+        assert_eq!(task.stack.len(), 8);
+
+        assert!(parse(&data[..data.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,296 @@
+//! Writer of ELF core files with the note layout GDB expects for Xtensa, the same as the
+//! ESP-IDF `espcoredump.py` uses.
+
+use crate::dump::{CoreDump, RegisterSource, Task, A0, LBEG, LCOUNT, LEND, PC, PS, SAR};
+
+const EM_XTENSA: u16 = 94;
+const ET_CORE: u16 = 4;
+const PT_LOAD: u32 = 1;
+const PT_NOTE: u32 = 4;
+const PF_W: u32 = 2;
+const PF_R: u32 = 4;
+const NT_PRSTATUS: u32 = 1;
+
+const EHDR_SIZE: usize = 52;
+const PHDR_SIZE: usize = 32;
+/// The size of `elf_prstatus` without the registers.
+const PRSTATUS_SIZE: usize = 72;
+
+/// The number of registers in `xtensa_elf_gregset_t`, GDB wants one more than it has.
+const GREG_COUNT: usize = 129;
+const GREG_PC: usize = 0;
+const GREG_PS: usize = 1;
+const GREG_LBEG: usize = 2;
+const GREG_LEND: usize = 3;
+const GREG_LCOUNT: usize = 4;
+const GREG_SAR: usize = 5;
+const GREG_AR: usize = 64;
+
+/// PS of a task running with windowed calls (`WOE`, `UM` and `CALLINC` 2), used for the
+/// dumping task whose PS isn't saved.
+const TASK_PS: u32 = 0x0006_0020;
+
+/// Build an ELF core file with a thread for every task that has registers, and the
+/// TCBs and stacks as memory.
+pub fn write_core(dump: &CoreDump) -> Vec<u8> {
+    let mut notes = Vec::new();
+    for task in &dump.tasks {
+        if task.source != RegisterSource::None {
+            write_note(&mut notes, b"CORE", NT_PRSTATUS, &prstatus(task));
+        }
+    }
+
+    let mut regions: Vec<(u32, &[u8])> = dump
+        .tasks
+        .iter()
+        .flat_map(|t| vec![(t.handle, &t.tcb[..]), (t.stack_addr, &t.stack[..])])
+        .filter(|(_, data)| !data.is_empty())
+        .collect();
+    regions.sort_by_key(|r| r.0);
+    // The dumped stacks may extend into the memory of the next region.
+    for i in 1..regions.len() {
+        let (start, next) = (regions[i - 1].0, regions[i].0);
+        let len = regions[i - 1]
+            .1
+            .len()
+            .min(next.saturating_sub(start) as usize);
+        regions[i - 1].1 = &regions[i - 1].1[..len];
+    }
+    regions.retain(|(_, data)| !data.is_empty());
+
+    let phnum = 1 + regions.len();
+    let mut offset = EHDR_SIZE + phnum * PHDR_SIZE;
+    let mut out = Vec::new();
+
+    // ELF header
+    out.extend(&[0x7f, b'E', b'L', b'F', 1, 1, 1, 0]);
+    out.extend(&[0; 8]);
+    push_u16(&mut out, ET_CORE);
+    push_u16(&mut out, EM_XTENSA);
+    push_u32(&mut out, 1);
+    push_u32(&mut out, 0);
+    push_u32(&mut out, EHDR_SIZE as u32);
+    push_u32(&mut out, 0);
+    push_u32(&mut out, 0);
+    push_u16(&mut out, EHDR_SIZE as u16);
+    push_u16(&mut out, PHDR_SIZE as u16);
+    push_u16(&mut out, phnum as u16);
+    push_u16(&mut out, 0);
+    push_u16(&mut out, 0);
+    push_u16(&mut out, 0);
+
+    // Program headers
+    write_phdr(&mut out, PT_NOTE, offset, 0, notes.len(), 0);
+    offset += notes.len();
+    for (addr, data) in &regions {
+        write_phdr(&mut out, PT_LOAD, offset, *addr, data.len(), PF_R | PF_W);
+        offset += data.len();
+    }
+
+    out.extend(&notes);
+    for (_, data) in &regions {
+        out.extend(*data);
+    }
+
+    out
+}
+
+/// `elf_prstatus` with the registers of `task`, identified by its TCB address.
+fn prstatus(task: &Task) -> Vec<u8> {
+    let r = &task.registers;
+    let mut gregs = [0; GREG_COUNT];
+    gregs[GREG_PC] = code_address(r[PC]);
+    gregs[GREG_PS] = match task.source {
+        RegisterSource::Current => TASK_PS,
+        _ => r[PS],
+    };
+    // The PS of a task in user mode (`UM`) can still have `EXCM` set from the exception
+    // entry, GDB can't unwind windowed calls with it.
+    if gregs[GREG_PS] & (1 << 5) != 0 {
+        gregs[GREG_PS] &= !(1 << 4);
+    }
+    gregs[GREG_LBEG] = r[LBEG];
+    gregs[GREG_LEND] = r[LEND];
+    gregs[GREG_LCOUNT] = r[LCOUNT];
+    gregs[GREG_SAR] = r[SAR];
+    gregs[GREG_AR..GREG_AR + 16].copy_from_slice(&r[A0..A0 + 16]);
+
+    let mut desc = vec![0; PRSTATUS_SIZE];
+    // `pr_pid`
+    desc[24..28].copy_from_slice(&task.handle.to_le_bytes());
+    for greg in gregs.iter() {
+        push_u32(&mut desc, *greg);
+    }
+    desc
+}
+
+/// Convert a windowed return address to the address of the code it points into.
+fn code_address(pc: u32) -> u32 {
+    if pc & 0x8000_0000 != 0 {
+        (pc & 0x3fff_ffff) | 0x4000_0000
+    } else {
+        pc
+    }
+}
+
+fn write_note(out: &mut Vec<u8>, name: &[u8], kind: u32, desc: &[u8]) {
+    push_u32(out, name.len() as u32 + 1);
+    push_u32(out, desc.len() as u32);
+    push_u32(out, kind);
+    out.extend(name);
+    out.push(0);
+    pad(out);
+    out.extend(desc);
+    pad(out);
+}
+
+fn write_phdr(out: &mut Vec<u8>, kind: u32, offset: usize, addr: u32, len: usize, flags: u32) {
+    push_u32(out, kind);
+    push_u32(out, offset as u32);
+    push_u32(out, addr);
+    push_u32(out, addr);
+    push_u32(out, len as u32);
+    push_u32(out, len as u32);
+    push_u32(out, flags);
+    push_u32(out, if kind == PT_LOAD { 4 } else { 0 });
+}
+
+/// Pad `out` to a multiple of 4 bytes.
+fn pad(out: &mut Vec<u8>) {
+    while out.len() & 3 != 0 {
+        out.push(0);
+    }
+}
+
+fn push_u16(out: &mut Vec<u8>, value: u16) {
+    out.extend(&value.to_le_bytes());
+}
+
+fn push_u32(out: &mut Vec<u8>, value: u32) {
+    out.extend(&value.to_le_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use super::{write_core, EHDR_SIZE, GREG_AR, GREG_PC, GREG_PS, PHDR_SIZE, PRSTATUS_SIZE};
+    use crate::dump::{CoreDump, HeapStats, RegisterSource, Task, A0, PC, PS, REGISTER_COUNT};
+
+    fn u16_at(data: &[u8], offset: usize) -> u16 {
+        u16::from_le_bytes(data[offset..offset + 2].try_into().unwrap())
+    }
+
+    fn u32_at(data: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+    }
+
+    fn task(handle: u32, source: RegisterSource, stack_addr: u32, stack: Vec<u8>) -> Task {
+        let mut registers = [0; REGISTER_COUNT];
+        registers[PC] = 0x800d_1234;
+        registers[PS] = 0x0006_0030;
+        for (i, register) in registers[A0..A0 + 16].iter_mut().enumerate() {
+            *register = i as u32;
+        }
+        Task {
+            handle,
+            name: String::from("task"),
+            state: 1,
+            priority: 5,
+            source,
+            stack_base: stack_addr,
+            registers,
+            tcb: vec![0xaa; 8],
+            stack_addr,
+            stack,
+        }
+    }
+
+    /// The type, file offset, address and length of the program headers of `elf`.
+    fn program_headers(elf: &[u8]) -> Vec<(u32, usize, u32, usize)> {
+        let phnum = u16_at(elf, 44) as usize;
+        (0..phnum)
+            .map(|i| {
+                let phdr = EHDR_SIZE + i * PHDR_SIZE;
+                let len = u32_at(elf, phdr + 16) as usize;
+                assert_eq!(u32_at(elf, phdr + 20) as usize, len);
+                (
+                    u32_at(elf, phdr),
+                    u32_at(elf, phdr + 4) as usize,
+                    u32_at(elf, phdr + 8),
+                    len,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn writes_a_core_file() {
+        let dump = CoreDump {
+            core_id: 0,
+            total_tasks: 3,
+            heap: HeapStats::default(),
+            tasks: vec![
+                task(
+                    0x3ffb_1000,
+                    RegisterSource::Exception,
+                    0x3ffb_2000,
+                    vec![1; 16],
+                ),
+                // Its stack runs into the TCB of the next task.
+                task(
+                    0x3ffb_3000,
+                    RegisterSource::Solicited,
+                    0x3ffb_2ff8,
+                    vec![2; 16],
+                ),
+                task(0x3ffb_4000, RegisterSource::None, 0, Vec::new()),
+            ],
+        };
+        let elf = write_core(&dump);
+
+        assert_eq!(elf[..4], *b"\x7fELF");
+        assert_eq!(u16_at(&elf, 16), 4);
+        assert_eq!(u16_at(&elf, 18), 94);
+
+        let phdrs = program_headers(&elf);
+        let loads: Vec<_> = phdrs[1..]
+            .iter()
+            .map(|&(kind, offset, addr, len)| {
+                assert_eq!(kind, 1);
+                (addr, &elf[offset..offset + len])
+            })
+            .collect();
+        assert_eq!(
+            loads,
+            [
+                (0x3ffb_1000, &[0xaa; 8][..]),
+                (0x3ffb_2000, &[1; 16][..]),
+                (0x3ffb_2ff8, &[2; 8][..]),
+                (0x3ffb_3000, &[0xaa; 8][..]),
+                (0x3ffb_4000, &[0xaa; 8][..]),
+            ]
+        );
+
+        // One `NT_PRSTATUS` note per task with registers.
+        let (kind, offset, _, len) = phdrs[0];
+        assert_eq!(kind, 4);
+        let note_len = 20 + PRSTATUS_SIZE + 129 * 4;
+        assert_eq!(len, 2 * note_len);
+        for (i, handle) in [0x3ffb_1000, 0x3ffb_3000].iter().enumerate() {
+            let note = &elf[offset + i * note_len..offset + (i + 1) * note_len];
+            assert_eq!(u32_at(note, 0), 5);
+            assert_eq!(u32_at(note, 8), 1);
+            assert_eq!(note[12..17], *b"CORE\0");
+
+            let desc = &note[20..];
+            assert_eq!(u32_at(desc, 24), *handle);
+            let greg = |n: usize| u32_at(desc, PRSTATUS_SIZE + n * 4);
+            assert_eq!(greg(GREG_PC), 0x400d_1234);
+            // `EXCM` is cleared as `UM` is set.
+            assert_eq!(greg(GREG_PS), 0x0006_0020);
+            assert_eq!(greg(GREG_AR + 1), 1);
+            assert_eq!(greg(GREG_AR + 15), 15);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Convert a core dump written by `freertos_esp32_sys::coredump` into an ELF core file.
+//!
+//! Usage: `freertos-esp32-coredump <dump> <core.elf>`
+//!
+//! Prints a summary of the tasks and the heap. The core file contains a thread per task
+//! and can be inspected with `xtensa-esp32-elf-gdb <firmware.elf> <core.elf>`.
+
+mod dump;
+mod elf;
+
+use std::env;
+use std::fs;
+
+use anyhow::{bail, Context, Result};
+
+use dump::{RegisterSource, A0, PC};
+
+fn main() -> Result<()> {
+    let args: Vec<_> = env::args().skip(1).collect();
+    let (input, output) = match args.as_slice() {
+        [input, output] => (input, output),
+        _ => bail!("usage: freertos-esp32-coredump <dump> <core.elf>"),
+    };
+
+    let data = fs::read(input).with_context(|| format!("failed to read {}", input))?;
+    let dump = dump::parse(&data)?;
+
+    println!("Dumped by core {}", dump.core_id);
+    if dump.tasks.len() < dump.total_tasks as usize {
+        println!(
+            "Truncated: {} of {} tasks dumped",
+            dump.tasks.len(),
+            dump.total_tasks
+        );
+    }
+    let heap = &dump.heap;
+    println!(
+        "Heap: {} of {} bytes used, {} free (peak {}, minimum free {}), {} allocations, {} frees",
+        heap.used,
+        heap.size,
+        heap.free,
+        heap.peak,
+        heap.min_ever_free,
+        heap.allocations,
+        heap.frees
+    );
+    println!("Tasks:");
+    for task in &dump.tasks {
+        print!(
+            "  {:#010x} {:<16} {:<9} priority {:>2}",
+            task.handle,
+            task.name,
+            task.state_name(),
+            task.priority
+        );
+        match task.source {
+            RegisterSource::None => println!("  (running on the other core)"),
+            _ => println!(
+                "  pc {:#010x} sp {:#010x} stack base {:#010x}",
+                task.registers[PC],
+                task.registers[A0 + 1],
+                task.stack_base
+            ),
+        }
+    }
+
+    fs::write(output, elf::write_core(&dump))
+        .with_context(|| format!("failed to write {}", output))?;
+
+    Ok(())
+}
@@ -1 +1,21 @@
-bindgen --use-core --size_t-is-usize --ctypes-prefix chlorine wrapper.h -o src/bindings.rs --generate-inline-functions -- -I"port" -I"port/esp32" -I"dep/xtensa/esp32/include" -I"dep/xtensa/include" -I"dep/FreeRTOS-Kernel/include" --sysroot=../../tools/gcc/xtensa-esp32-elf -target xtensa-esp32-none-elf
\ No newline at end of file
+# Usage: FREERTOS_CONFIG_DIR=<dir> ./generate_bindings.sh [esp32|esp32s2|esp32s3]
+#
+# `FreeRTOSConfig.h` includes the `freertos_config.h` that build.rs generates into
+# `$OUT_DIR/include`, build the crate for the same chip with the default settings first
+# and pass that directory. The generated settings are left out of the bindings, they
+# are emitted by `src/config.rs` instead. build.rs writes their names to
+# `freertos_config.regex` next to the header.
+# The `bindgen` feature generates the bindings in build.rs with the same options, see
+# `build_bindings.rs`.
+chip=${1:-esp32}
+if [ -z "$FREERTOS_CONFIG_DIR" ]; then
+    echo "FREERTOS_CONFIG_DIR must point to the directory of the generated freertos_config.h" >&2
+    exit 1
+fi
+generated=$(cat "$FREERTOS_CONFIG_DIR/freertos_config.regex") || exit 1
+bindgen --use-core --size_t-is-usize --ctypes-prefix chlorine wrapper.h -o src/bindings/$chip.rs \
+    --generate-inline-functions --blocklist-item "$generated" \
+    --blocklist-function pvPortMallocAligned -- \
+    -I"$FREERTOS_CONFIG_DIR" -I"port" -I"port/esp32" -I"dep/xtensa/$chip/include" \
+    -I"dep/xtensa/include" -I"dep/FreeRTOS-Kernel/include" \
+    --sysroot=../../tools/gcc/xtensa-$chip-elf -target xtensa-$chip-none-elf
@@ -70,14 +70,15 @@
 #ifndef FREERTOS_CONFIG_H
 #define FREERTOS_CONFIG_H
 
+/* The tunable settings, generated by build.rs from the cargo features and the
+   FREERTOS_* environment variables. */
+#include "freertos_config.h"
+
 /* enable use of optimized task selection by the scheduler */
 #ifdef CONFIG_FREERTOS_OPTIMIZED_SCHEDULER
     #define configUSE_PORT_OPTIMISED_TASK_SELECTION 1
 #endif
 
-/* ESP31 and ESP32 are dualcore processors. */
-#define configNUM_CORES 2
-
 #define XT_USE_THREAD_SAFE_CLIB 0
 #define portUSING_MPU_WRAPPERS  0
 #define configUSE_MUTEX         1
@@ -103,14 +104,10 @@
 
     #if defined(CONFIG_FREERTOS_ASSERT_DISABLE)
         #define configASSERT(a) /* assertions disabled */
-    #elif defined(CONFIG_FREERTOS_ASSERT_FAIL_PRINT_CONTINUE)
-        #define configASSERT(a)                                                \
-            if (unlikely(!(a))) {                                              \
-                ets_printf("%s:%d (%s)- assert failed!\n", __FILE__, __LINE__, \
-                           __FUNCTION__);                                      \
-            }
-    #else /* CONFIG_FREERTOS_ASSERT_FAIL_ABORT */
+    #else
 
+/* Calls the registered Rust assert handler, only returns with the
+ * `assert-continue` feature. */
 void vPortPanic(const char *file, size_t file_len, size_t line,
                 const char *func, size_t func_len);
 
@@ -152,49 +149,9 @@ void vPortPanic(const char *file, size_t file_len, size_t line,
 #define configUSE_IDLE_HOOK  0
 #define configUSE_TICK_HOOK  0
 
-#define configTICK_RATE_HZ (100)
-
 /* Default clock rate for simulator */
 //#define configCPU_CLOCK_HZ				80000000
 
-/* This has impact on speed of search for highest priority */
-#ifdef SMALL_TEST
-    #define configMAX_PRIORITIES (7)
-#else
-    #define configMAX_PRIORITIES (25)
-#endif
-
-#ifndef CONFIG_APPTRACE_ENABLE
-    #define configMINIMAL_STACK_SIZE 768
-#else
-    /* apptrace module requires at least 2KB of stack per task */
-    #define configMINIMAL_STACK_SIZE 2048
-#endif
-
-#ifndef configIDLE_TASK_STACK_SIZE
-    #define configIDLE_TASK_STACK_SIZE 768
-#endif
-
-/* The Xtensa port uses a separate interrupt stack. Adjust the stack size */
-/* to suit the needs of your specific application.                        */
-#ifndef configISR_STACK_SIZE
-    #define configISR_STACK_SIZE 1536
-#endif
-
-/* Minimal heap size to make sure examples can run on memory limited
-   configs. Adjust this to suit your system. */
-
-// We define the heap to span all of the non-statically-allocated shared RAM.
-// ToDo: Make sure there is some space left for the app and main cpu when
-// running outside of a thread.
-#define configMAX_TASK_NAME_LEN (16)
-
-#ifdef CONFIG_FREERTOS_USE_TRACE_FACILITY
-    #define configUSE_TRACE_FACILITY                                           \
-        1 /* Used by uxTaskGetSystemState(), and other trace facility          \
-             functions */
-#endif
-
 #ifdef CONFIG_FREERTOS_USE_STATS_FORMATTING_FUNCTIONS
     #define configUSE_STATS_FORMATTING_FUNCTIONS 1 /* Used by vTaskList() */
 #endif
@@ -213,21 +170,11 @@ void vPortPanic(const char *file, size_t file_len, size_t line,
 #define configBENCHMARK            0 /* Provided by Xtensa port patch */
 #define configUSE_16_BIT_TICKS     0
 #define configIDLE_SHOULD_YIELD    0
-#define configQUEUE_REGISTRY_SIZE  0
 
 #define configUSE_MUTEXES             1
 #define configUSE_RECURSIVE_MUTEXES   1
 #define configUSE_COUNTING_SEMAPHORES 1
 
-#define CONFIG_FREERTOS_CHECK_STACKOVERFLOW_CANARY 1
-#if CONFIG_FREERTOS_CHECK_STACKOVERFLOW_NONE
-    #define configCHECK_FOR_STACK_OVERFLOW 0
-#elif CONFIG_FREERTOS_CHECK_STACKOVERFLOW_PTRVAL
-    #define configCHECK_FOR_STACK_OVERFLOW 1
-#elif CONFIG_FREERTOS_CHECK_STACKOVERFLOW_CANARY
-    #define configCHECK_FOR_STACK_OVERFLOW 2
-#endif
-
 /* Co-routine definitions. */
 #define configUSE_CO_ROUTINES           0
 #define configMAX_CO_ROUTINE_PRIORITIES 2
@@ -243,6 +190,7 @@ void vPortPanic(const char *file, size_t file_len, size_t line,
 #define INCLUDE_vTaskDelayUntil             1
 #define INCLUDE_vTaskDelay                  1
 #define INCLUDE_uxTaskGetStackHighWaterMark 1
+#define INCLUDE_uxTaskGetStackHighWaterMark2 1
 #define INCLUDE_pcTaskGetTaskName           1
 #define INCLUDE_xTaskGetIdleTaskHandle      1
 #define INCLUDE_pxTaskGetStackStart         1
@@ -261,7 +209,6 @@ void vPortPanic(const char *file, size_t file_len, size_t line,
 //#define configUSE_NEWLIB_REENTRANT          1
 
 #define configSUPPORT_DYNAMIC_ALLOCATION 1
-#define configSUPPORT_STATIC_ALLOCATION  0
 
 #if !defined(__ASSEMBLER__) &&                                                 \
     defined(CONFIG_FREERTOS_ENABLE_STATIC_TASK_CLEAN_UP)
@@ -271,10 +218,7 @@ extern void vPortCleanUpTCB(void *pxTCB);
 
 /* Test FreeRTOS timers (with timer task) and more. */
 /* Some files don't compile if this flag is disabled */
-#define configUSE_TIMERS             1
-#define configTIMER_TASK_PRIORITY    1
-#define configTIMER_QUEUE_LENGTH     10
-#define configTIMER_TASK_STACK_DEPTH 1024
+#define configUSE_TIMERS 1
 
 #define INCLUDE_xTimerPendFunctionCall 1
 #define INCLUDE_eTaskGetState          1
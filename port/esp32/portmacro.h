@@ -85,6 +85,11 @@ void vPortGiveISRLock(void);
 void vPortTakeTaskLock(void);
 void vPortGiveTaskLock(void);
 
+#if defined(CONFIG_FREERTOS_RUST_ALLOC)
+// Only implemented by the `use-rust-alloc` allocator, the kernel heaps have no aligned variant.
+void *pvPortMallocAligned(size_t xSize, size_t xAlignment);
+#endif
+
 extern volatile uint32_t port_scheduler_running[configNUM_CORES];
 extern volatile uint32_t port_interrupt_nesting[configNUM_CORES];
 
@@ -0,0 +1,86 @@
+/* FreeRTOSConfig.h of the `host-sim` mode, which builds the kernel with the POSIX port
+   (portable/ThirdParty/GCC/Posix): every task is a thread and the tick is a signal.
+
+   The kernel API options match `port/FreeRTOSConfig.h`, so code that runs in the
+   simulation uses the same kernel functions as on the chip. The simulation runs a
+   single core, the SMP kernel provides the defaults of the multi core port macros
+   (`portGET_CORE_ID`, the task and ISR locks, ...) for `configNUM_CORES == 1`. */
+
+#ifndef FREERTOS_CONFIG_H
+#define FREERTOS_CONFIG_H
+
+/* The tunable settings, generated by build.rs from the cargo features and the
+   FREERTOS_* environment variables. */
+#include "freertos_config.h"
+
+#include <stddef.h>
+#include <string.h>
+
+/* configASSERT behaviour */
+#if defined(CONFIG_FREERTOS_ASSERT_DISABLE)
+    #define configASSERT(a) /* assertions disabled */
+#else
+/* Calls the registered Rust assert handler, only returns with the
+ * `assert-continue` feature. */
+void vPortPanic(const char *file, size_t file_len, size_t line,
+                const char *func, size_t func_len);
+    #define configASSERT(a)                                                    \
+        if (!(a)) {                                                            \
+            vPortPanic(__FILE__, strlen(__FILE__), __LINE__, __FUNCTION__,     \
+                       strlen(__FUNCTION__));                                  \
+        }
+#endif
+
+#define configUSE_PREEMPTION    1
+#define configUSE_IDLE_HOOK     0
+#define configUSE_TICK_HOOK     0
+#define configUSE_16_BIT_TICKS  0
+#define configIDLE_SHOULD_YIELD 0
+
+#define configNUM_THREAD_LOCAL_STORAGE_POINTERS     1
+#define configTHREAD_LOCAL_STORAGE_DELETE_CALLBACKS 1
+
+#define configUSE_MUTEXES             1
+#define configUSE_RECURSIVE_MUTEXES   1
+#define configUSE_COUNTING_SEMAPHORES 1
+
+/* Co-routine definitions. */
+#define configUSE_CO_ROUTINES           0
+#define configMAX_CO_ROUTINE_PRIORITIES 2
+
+/* Set the following definitions to 1 to include the API function, or zero
+   to exclude the API function. */
+
+#define INCLUDE_vTaskPrioritySet            1
+#define INCLUDE_uxTaskPriorityGet           1
+#define INCLUDE_vTaskDelete                 1
+#define INCLUDE_vTaskCleanUpResources       0
+#define INCLUDE_vTaskSuspend                1
+#define INCLUDE_vTaskDelayUntil             1
+#define INCLUDE_vTaskDelay                  1
+#define INCLUDE_uxTaskGetStackHighWaterMark 1
+#define INCLUDE_uxTaskGetStackHighWaterMark2 1
+#define INCLUDE_pcTaskGetTaskName           1
+#define INCLUDE_xTaskGetIdleTaskHandle      1
+#define INCLUDE_pxTaskGetStackStart         1
+
+#define INCLUDE_xSemaphoreGetMutexHolder 1
+
+#define configSUPPORT_DYNAMIC_ALLOCATION 1
+
+#define configUSE_TIMERS 1
+
+#define INCLUDE_xTimerPendFunctionCall 1
+#define INCLUDE_eTaskGetState          1
+#define configUSE_QUEUE_SETS           1
+
+/* The POSIX port has no tickless idle, the tick is a timer signal. */
+#define configUSE_TICKLESS_IDLE 0
+
+#define configCHECK_MUTEX_GIVEN_BY_OWNER 0
+
+/* Core affinities are ignored on a single core, like on the ESP32-S2. */
+#define configUSE_CORE_AFFINITY       0
+#define configRUN_MULTIPLE_PRIORITIES 0
+
+#endif /* FREERTOS_CONFIG_H */
@@ -9,6 +9,9 @@ void vTaskExitCriticalFromISR(UBaseType_t uxSavedInterruptStatus) {
     taskEXIT_CRITICAL_FROM_ISR(uxSavedInterruptStatus);
 }
 
+#ifdef __XTENSA__
+/* There is no libc on the chip, the `host-sim` mode links the one of the host. */
 size_t strlen(const char* str) {
     return __builtin_strlen(str);
 }
+#endif
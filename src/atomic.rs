@@ -0,0 +1,174 @@
+//! The read-modify-write atomics used by the crate.
+//!
+//! The ESP32-S2 has no compare-and-set instruction (`XCHAL_HAVE_S32C1I` is 0), so its
+//! atomics only support loads and stores, which is also why `spin` isn't used there. As
+//! the S2 has a single core, the operations mask interrupts around a load and a store
+//! instead. The other chips and the host use the native atomics.
+
+// Which of the operations are used depends on the enabled features.
+#![allow(dead_code, unused_imports)]
+
+pub(crate) use imp::*;
+
+#[cfg(not(feature = "esp32s2"))]
+mod imp {
+    use core::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+
+    pub use spin::Once;
+
+    #[inline]
+    pub fn swap(atomic: &AtomicBool, val: bool) -> bool {
+        atomic.swap(val, Ordering::AcqRel)
+    }
+
+    #[inline]
+    pub fn compare_exchange<T>(
+        atomic: &AtomicPtr<T>,
+        current: *mut T,
+        new: *mut T,
+    ) -> Result<*mut T, *mut T> {
+        atomic.compare_exchange(current, new, Ordering::AcqRel, Ordering::Acquire)
+    }
+
+    #[inline]
+    pub fn fetch_add(atomic: &AtomicUsize, val: usize) -> usize {
+        atomic.fetch_add(val, Ordering::Relaxed)
+    }
+
+    #[inline]
+    pub fn fetch_sub(atomic: &AtomicUsize, val: usize) -> usize {
+        atomic.fetch_sub(val, Ordering::Relaxed)
+    }
+
+    #[inline]
+    pub fn fetch_max(atomic: &AtomicUsize, val: usize) -> usize {
+        atomic.fetch_max(val, Ordering::Relaxed)
+    }
+}
+
+#[cfg(feature = "esp32s2")]
+mod imp {
+    use core::cell::UnsafeCell;
+    use core::mem::MaybeUninit;
+    use core::sync::atomic::{AtomicBool, AtomicPtr, AtomicU8, AtomicUsize, Ordering};
+
+    use crate::{portDISABLE_INTERRUPTS, portRESTORE_INTERRUPTS};
+
+    /// Run `f` with interrupts masked, so that nothing else runs on the only core.
+    ///
+    /// The relaxed loads and stores in `f` are ordered by the `memory` clobber of the
+    /// masking.
+    #[inline]
+    fn masked<R>(f: impl FnOnce() -> R) -> R {
+        let state = unsafe { portDISABLE_INTERRUPTS() };
+        let res = f();
+        unsafe { portRESTORE_INTERRUPTS(state) };
+        res
+    }
+
+    #[inline]
+    pub fn swap(atomic: &AtomicBool, val: bool) -> bool {
+        masked(|| {
+            let prev = atomic.load(Ordering::Relaxed);
+            atomic.store(val, Ordering::Relaxed);
+            prev
+        })
+    }
+
+    #[inline]
+    pub fn compare_exchange<T>(
+        atomic: &AtomicPtr<T>,
+        current: *mut T,
+        new: *mut T,
+    ) -> Result<*mut T, *mut T> {
+        masked(|| {
+            let prev = atomic.load(Ordering::Relaxed);
+            if prev == current {
+                atomic.store(new, Ordering::Relaxed);
+                Ok(prev)
+            } else {
+                Err(prev)
+            }
+        })
+    }
+
+    #[inline]
+    fn update(atomic: &AtomicUsize, f: impl FnOnce(usize) -> usize) -> usize {
+        masked(|| {
+            let prev = atomic.load(Ordering::Relaxed);
+            atomic.store(f(prev), Ordering::Relaxed);
+            prev
+        })
+    }
+
+    #[inline]
+    pub fn fetch_add(atomic: &AtomicUsize, val: usize) -> usize {
+        update(atomic, |prev| prev.wrapping_add(val))
+    }
+
+    #[inline]
+    pub fn fetch_sub(atomic: &AtomicUsize, val: usize) -> usize {
+        update(atomic, |prev| prev.wrapping_sub(val))
+    }
+
+    #[inline]
+    pub fn fetch_max(atomic: &AtomicUsize, val: usize) -> usize {
+        update(atomic, |prev| prev.max(val))
+    }
+
+    const INCOMPLETE: u8 = 0;
+    const RUNNING: u8 = 1;
+    const COMPLETE: u8 = 2;
+
+    /// The subset of `spin::Once` used by the crate.
+    pub struct Once<T = ()> {
+        state: AtomicU8,
+        value: UnsafeCell<MaybeUninit<T>>,
+    }
+
+    unsafe impl<T: Send> Send for Once<T> {}
+    unsafe impl<T: Send + Sync> Sync for Once<T> {}
+
+    impl<T> Once<T> {
+        pub const fn new() -> Self {
+            Once {
+                state: AtomicU8::new(INCOMPLETE),
+                value: UnsafeCell::new(MaybeUninit::uninit()),
+            }
+        }
+
+        /// Run `f` if no call ran it yet, and wait until the value is set otherwise.
+        pub fn call_once(&self, f: impl FnOnce() -> T) -> &T {
+            loop {
+                match self.state.load(Ordering::Acquire) {
+                    // Safe because the value is never written again once complete.
+                    COMPLETE => return unsafe { &*(*self.value.get()).as_ptr() },
+                    RUNNING => core::hint::spin_loop(),
+                    _ => {
+                        let claimed = masked(|| {
+                            let free = self.state.load(Ordering::Relaxed) == INCOMPLETE;
+                            if free {
+                                self.state.store(RUNNING, Ordering::Relaxed);
+                            }
+                            free
+                        });
+                        if claimed {
+                            // Safe because only the call that claimed the state writes.
+                            unsafe { (*self.value.get()).as_mut_ptr().write(f()) };
+                            self.state.store(COMPLETE, Ordering::Release);
+                            return unsafe { &*(*self.value.get()).as_ptr() };
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    impl<T> Drop for Once<T> {
+        fn drop(&mut self) {
+            if *self.state.get_mut() == COMPLETE {
+                unsafe { core::ptr::drop_in_place((*self.value.get()).as_mut_ptr()) };
+            }
+        }
+    }
+}
@@ -1,125 +1,377 @@
 //! Backtrace support for printing stack pointers and program counters.
+//!
+//! This is the chip specific part: the memory map, where the unwind starts and reading
+//! the memory of the chip. The unwinder itself is in the [`unwind`](crate::unwind)
+//! module.
 
-use core::ffi::c_void;
+use core::fmt;
 
-/// A frame in the backtrace
-#[derive(Debug)]
-pub struct BacktraceFrame {
-    /// The address of the last instruction in the backtrace (program counter).
-    pub pc: u32,
-    /// The address of the current stack frame in the backtrace (stack pointer).
-    pub sp: u32,
-}
+use crate::task::Task;
+use crate::unwind::Unwinder;
+use crate::{
+    eTaskState, eTaskState_eBlocked, eTaskState_eDeleted, eTaskState_eReady, eTaskState_eRunning,
+    eTaskState_eSuspended, uxTaskGetNumberOfTasks, uxTaskGetSystemState, vTaskSuspendAll,
+    xTaskGetCurrentTaskHandle, xTaskResumeAll, TaskHandle_t, TaskStatus_t, XtExcFrame, XtSolFrame,
+};
 
-impl core::fmt::Display for BacktraceFrame {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{:#08x}:{:#08x}", self.pc, self.sp)
-    }
-}
+pub use crate::unwind::{BacktraceFrame, StackMemory, Truncation, DEFAULT_MAX_DEPTH};
+
+/// The maximum number of tasks printed by [`dump_all_tasks`].
+pub const MAX_DUMPED_TASKS: usize = 32;
 
 impl BacktraceFrame {
     /// Check if `pc` and `sp` are sane.
     ///
-    /// Checks if the stack pointer is located in dram (data ram) and if the program
-    /// counter is in an executable memory space.
+    /// Checks if the stack pointer is located in dram (data ram) and 16 byte aligned,
+    /// and if the program counter is in an executable memory space.
     ///
     /// Taken from `esp-idf/components/xtensa/debug_helpers.c
     pub fn is_sane(&self) -> bool {
         let sp_in_dram = {
             let sp = self.sp as usize;
-            !(sp < (SOC_DRAM_LOW + 0x10) || sp > (SOC_DRAM_HIGH - 0x10))
+            !(sp < (SOC_DRAM_LOW + 0x10) || sp > (SOC_DRAM_HIGH - 0x10) || (sp & 0xF) != 0)
         };
 
-        sp_in_dram// && is_pointer_executable(self.pc as usize)
+        sp_in_dram && is_pointer_executable(self.pc as usize)
+    }
+}
+
+/// The name of an exception cause (the `EXCCAUSE` register).
+///
+/// Taken from `esp-idf/components/esp_system/port/arch/xtensa/panic_arch.c`
+pub fn exception_cause_name(exccause: u32) -> &'static str {
+    const NAMES: [&str; 40] = [
+        "IllegalInstruction",
+        "Syscall",
+        "InstructionFetchError",
+        "LoadStoreError",
+        "Level1Interrupt",
+        "Alloca",
+        "IntegerDivideByZero",
+        "PCValue",
+        "Privileged",
+        "LoadStoreAlignment",
+        "res",
+        "res",
+        "InstrPDAddrError",
+        "LoadStorePIFDataError",
+        "InstrPIFAddrError",
+        "LoadStorePIFAddrError",
+        "InstTLBMiss",
+        "InstTLBMultiHit",
+        "InstFetchPrivilege",
+        "res",
+        "InstrFetchProhibited",
+        "res",
+        "res",
+        "res",
+        "LoadStoreTLBMiss",
+        "LoadStoreTLBMultihit",
+        "LoadStorePrivilege",
+        "res",
+        "LoadProhibited",
+        "StoreProhibited",
+        "res",
+        "res",
+        "Cp0Dis",
+        "Cp1Dis",
+        "Cp2Dis",
+        "Cp3Dis",
+        "Cp4Dis",
+        "Cp5Dis",
+        "Cp6Dis",
+        "Cp7Dis",
+    ];
+
+    NAMES.get(exccause as usize).copied().unwrap_or("Unknown")
+}
+
+/// Prints the exception frame like the ESP-IDF panic handler: the exception cause, the
+/// register file and the backtrace.
+impl fmt::Display for XtExcFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Guru Meditation Error: Core panic'ed ({}). Exception was unhandled.",
+            exception_cause_name(self.exccause)
+        )?;
+        writeln!(f, "Register dump:")?;
+
+        let registers = [
+            ("PC", self.pc),
+            ("PS", self.ps),
+            ("A0", self.a0),
+            ("A1", self.a1),
+            ("A2", self.a2),
+            ("A3", self.a3),
+            ("A4", self.a4),
+            ("A5", self.a5),
+            ("A6", self.a6),
+            ("A7", self.a7),
+            ("A8", self.a8),
+            ("A9", self.a9),
+            ("A10", self.a10),
+            ("A11", self.a11),
+            ("A12", self.a12),
+            ("A13", self.a13),
+            ("A14", self.a14),
+            ("A15", self.a15),
+            ("SAR", self.sar),
+            ("EXCCAUSE", self.exccause),
+            ("EXCVADDR", self.excvaddr),
+        ];
+        #[cfg(not(feature = "esp32s2"))]
+        let loop_registers = [
+            ("LBEG", self.lbeg),
+            ("LEND", self.lend),
+            ("LCOUNT", self.lcount),
+        ];
+        // The ESP32-S2 has no zero-overhead loops.
+        #[cfg(feature = "esp32s2")]
+        let loop_registers: [(&str, u32); 0] = [];
+
+        let mut registers = registers.iter().chain(loop_registers.iter()).peekable();
+        while registers.peek().is_some() {
+            for (name, value) in registers.by_ref().take(4) {
+                write!(f, "{:<8}: {:#010x}  ", name, value)?;
+            }
+            writeln!(f)?;
+        }
+
+        write!(f, "\nBacktrace:")?;
+        for frame in Backtrace::from_exception_frame(self) {
+            write!(f, "{} ", frame)?;
+        }
+        Ok(())
     }
 }
 
 /// Wether or not the supplied address is in an executable memory space.
 pub fn is_pointer_executable(ptr: usize) -> bool {
+    // Only the ESP32 executes from the per core cache regions.
+    #[cfg(feature = "esp32")]
+    if (ptr >= SOC_CACHE_APP_LOW && ptr < SOC_CACHE_APP_HIGH)
+        || (ptr >= SOC_CACHE_PRO_LOW && ptr < SOC_CACHE_PRO_HIGH)
+    {
+        return true;
+    }
+
     (ptr >= SOC_IROM_LOW && ptr < SOC_IROM_HIGH)
         || (ptr >= SOC_IRAM_LOW && ptr < SOC_IRAM_HIGH)
         || (ptr >= SOC_IROM_MASK_LOW && ptr < SOC_IROM_MASK_HIGH)
-        || (ptr >= SOC_CACHE_APP_LOW && ptr < SOC_CACHE_APP_HIGH)
-        || (ptr >= SOC_CACHE_PRO_LOW && ptr < SOC_CACHE_PRO_HIGH)
         || (ptr >= SOC_RTC_IRAM_LOW && ptr < SOC_RTC_IRAM_HIGH)
 }
 
-// Memory map
-// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-
-/// Data ROM starting address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_DROM_LOW: usize = 0x3F400000;
-/// Data ROM ending address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_DROM_HIGH: usize = 0x3F800000;
-/// Data RAM starting address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_DRAM_LOW: usize = 0x3FFAE000;
-/// Data RAM ending address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_DRAM_HIGH: usize = 0x40000000;
-/// Instruction ROM starting address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_IROM_LOW: usize = 0x400D0000;
-/// Instruction ROM ending address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_IROM_HIGH: usize = 0x40400000;
-/// Instruction ROM mask starting address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_IROM_MASK_LOW: usize = 0x40000000;
-/// Instruction ROM mask ending address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_IROM_MASK_HIGH: usize = 0x40064F00;
-/// Pro cpu cache starting address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_CACHE_PRO_LOW: usize = 0x40070000;
-/// Pro cpu cache ending address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_CACHE_PRO_HIGH: usize = 0x40078000;
-/// App cpu cache starting address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_CACHE_APP_LOW: usize = 0x40078000;
-/// App cpu cache ending address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_CACHE_APP_HIGH: usize = 0x40080000;
-/// Instruction RAM starting address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_IRAM_LOW: usize = 0x40080000;
-/// Instruction RAM ending address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_IRAM_HIGH: usize = 0x400A0000;
-/// RTC instruction RAM starting address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_RTC_IRAM_LOW: usize = 0x400C0000;
-/// RTC instruction RAM ending address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_RTC_IRAM_HIGH: usize = 0x400C2000;
-/// Rtc data ram starting address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_RTC_DRAM_LOW: usize = 0x3FF80000;
-/// Rtc data ram ending address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_RTC_DRAM_HIGH: usize = 0x3FF82000;
-/// Rtc data starting address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_RTC_DATA_LOW: usize = 0x50000000;
-/// Rtc data ending address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_RTC_DATA_HIGH: usize = 0x50002000;
-/// External RAM starting address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_EXTRAM_DATA_LOW: usize = 0x3F800000;
-/// External RAM ending address
-/// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
-pub const SOC_EXTRAM_DATA_HIGH: usize = 0x3FC00000;
-
-/// A backtrace iterator that returns [`BacktraceFrame`]s.
-pub struct Backtrace {
-    pc: u32,
-    sp: u32,
-    next_pc: u32,
-    last: bool,
+// Memory map of the selected chip
+#[cfg(feature = "esp32")]
+pub use esp32_memory_map::*;
+#[cfg(feature = "esp32s2")]
+pub use esp32s2_memory_map::*;
+#[cfg(feature = "esp32s3")]
+pub use esp32s3_memory_map::*;
+
+#[cfg(feature = "esp32")]
+mod esp32_memory_map {
+    /// Data ROM starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_DROM_LOW: usize = 0x3F400000;
+    /// Data ROM ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_DROM_HIGH: usize = 0x3F800000;
+    /// Data RAM starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_DRAM_LOW: usize = 0x3FFAE000;
+    /// Data RAM ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_DRAM_HIGH: usize = 0x40000000;
+    /// Instruction ROM starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_IROM_LOW: usize = 0x400D0000;
+    /// Instruction ROM ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_IROM_HIGH: usize = 0x40400000;
+    /// Instruction ROM mask starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_IROM_MASK_LOW: usize = 0x40000000;
+    /// Instruction ROM mask ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_IROM_MASK_HIGH: usize = 0x40064F00;
+    /// Pro cpu cache starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_CACHE_PRO_LOW: usize = 0x40070000;
+    /// Pro cpu cache ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_CACHE_PRO_HIGH: usize = 0x40078000;
+    /// App cpu cache starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_CACHE_APP_LOW: usize = 0x40078000;
+    /// App cpu cache ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_CACHE_APP_HIGH: usize = 0x40080000;
+    /// Instruction RAM starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_IRAM_LOW: usize = 0x40080000;
+    /// Instruction RAM ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_IRAM_HIGH: usize = 0x400A0000;
+    /// RTC instruction RAM starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_RTC_IRAM_LOW: usize = 0x400C0000;
+    /// RTC instruction RAM ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_RTC_IRAM_HIGH: usize = 0x400C2000;
+    /// Rtc data ram starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_RTC_DRAM_LOW: usize = 0x3FF80000;
+    /// Rtc data ram ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_RTC_DRAM_HIGH: usize = 0x3FF82000;
+    /// Rtc data starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_RTC_DATA_LOW: usize = 0x50000000;
+    /// Rtc data ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_RTC_DATA_HIGH: usize = 0x50002000;
+    /// External RAM starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_EXTRAM_DATA_LOW: usize = 0x3F800000;
+    /// External RAM ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32/include/soc/soc.h`
+    pub const SOC_EXTRAM_DATA_HIGH: usize = 0x3FC00000;
+}
+
+#[cfg(feature = "esp32s2")]
+mod esp32s2_memory_map {
+    /// Data ROM starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32s2/include/soc/soc.h`
+    pub const SOC_DROM_LOW: usize = 0x3F000000;
+    /// Data ROM ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32s2/include/soc/soc.h`
+    pub const SOC_DROM_HIGH: usize = 0x3FF80000;
+    /// Data RAM starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32s2/include/soc/soc.h`
+    pub const SOC_DRAM_LOW: usize = 0x3FFB0000;
+    /// Data RAM ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32s2/include/soc/soc.h`
+    pub const SOC_DRAM_HIGH: usize = 0x40000000;
+    /// Instruction ROM starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32s2/include/soc/soc.h`
+    pub const SOC_IROM_LOW: usize = 0x40080000;
+    /// Instruction ROM ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32s2/include/soc/soc.h`
+    pub const SOC_IROM_HIGH: usize = 0x40800000;
+    /// Instruction ROM mask starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32s2/include/soc/soc.h`
+    pub const SOC_IROM_MASK_LOW: usize = 0x40000000;
+    /// Instruction ROM mask ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32s2/include/soc/soc.h`
+    pub const SOC_IROM_MASK_HIGH: usize = 0x4001A100;
+    /// Instruction RAM starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32s2/include/soc/soc.h`
+    pub const SOC_IRAM_LOW: usize = 0x40020000;
+    /// Instruction RAM ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32s2/include/soc/soc.h`
+    pub const SOC_IRAM_HIGH: usize = 0x40070000;
+    /// RTC instruction RAM starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32s2/include/soc/soc.h`
+    pub const SOC_RTC_IRAM_LOW: usize = 0x40070000;
+    /// RTC instruction RAM ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32s2/include/soc/soc.h`
+    pub const SOC_RTC_IRAM_HIGH: usize = 0x40072000;
+    /// Rtc data ram starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32s2/include/soc/soc.h`
+    pub const SOC_RTC_DRAM_LOW: usize = 0x3FF9E000;
+    /// Rtc data ram ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32s2/include/soc/soc.h`
+    pub const SOC_RTC_DRAM_HIGH: usize = 0x3FFA0000;
+    /// Rtc data starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32s2/include/soc/soc.h`
+    pub const SOC_RTC_DATA_LOW: usize = 0x50000000;
+    /// Rtc data ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32s2/include/soc/soc.h`
+    pub const SOC_RTC_DATA_HIGH: usize = 0x50002000;
+    /// External RAM starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32s2/include/soc/soc.h`
+    pub const SOC_EXTRAM_DATA_LOW: usize = 0x3F500000;
+    /// External RAM ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32s2/include/soc/soc.h`
+    pub const SOC_EXTRAM_DATA_HIGH: usize = 0x3FF80000;
+}
+
+#[cfg(feature = "esp32s3")]
+mod esp32s3_memory_map {
+    /// Data ROM starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32s3/include/soc/soc.h`
+    pub const SOC_DROM_LOW: usize = 0x3C000000;
+    /// Data ROM ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32s3/include/soc/soc.h`
+    pub const SOC_DROM_HIGH: usize = 0x3D000000;
+    /// Data RAM starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32s3/include/soc/soc.h`
+    pub const SOC_DRAM_LOW: usize = 0x3FC88000;
+    /// Data RAM ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32s3/include/soc/soc.h`
+    pub const SOC_DRAM_HIGH: usize = 0x3FD00000;
+    /// Instruction ROM starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32s3/include/soc/soc.h`
+    pub const SOC_IROM_LOW: usize = 0x42000000;
+    /// Instruction ROM ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32s3/include/soc/soc.h`
+    pub const SOC_IROM_HIGH: usize = 0x44000000;
+    /// Instruction ROM mask starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32s3/include/soc/soc.h`
+    pub const SOC_IROM_MASK_LOW: usize = 0x40000000;
+    /// Instruction ROM mask ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32s3/include/soc/soc.h`
+    pub const SOC_IROM_MASK_HIGH: usize = 0x40060000;
+    /// Instruction RAM starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32s3/include/soc/soc.h`
+    pub const SOC_IRAM_LOW: usize = 0x40370000;
+    /// Instruction RAM ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32s3/include/soc/soc.h`
+    pub const SOC_IRAM_HIGH: usize = 0x403E0000;
+    /// RTC instruction RAM starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32s3/include/soc/soc.h`
+    pub const SOC_RTC_IRAM_LOW: usize = 0x600FE000;
+    /// RTC instruction RAM ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32s3/include/soc/soc.h`
+    pub const SOC_RTC_IRAM_HIGH: usize = 0x60100000;
+    /// Rtc data ram starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32s3/include/soc/soc.h`
+    pub const SOC_RTC_DRAM_LOW: usize = 0x600FE000;
+    /// Rtc data ram ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32s3/include/soc/soc.h`
+    pub const SOC_RTC_DRAM_HIGH: usize = 0x60100000;
+    /// Rtc data starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32s3/include/soc/soc.h`
+    pub const SOC_RTC_DATA_LOW: usize = 0x50000000;
+    /// Rtc data ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32s3/include/soc/soc.h`
+    pub const SOC_RTC_DATA_HIGH: usize = 0x50002000;
+    /// External RAM starting address
+    /// Taken from `esp-idf/components/soc/soc/esp32s3/include/soc/soc.h`
+    pub const SOC_EXTRAM_DATA_LOW: usize = 0x3D000000;
+    /// External RAM ending address
+    /// Taken from `esp-idf/components/soc/soc/esp32s3/include/soc/soc.h`
+    pub const SOC_EXTRAM_DATA_HIGH: usize = 0x3E000000;
+}
+
+/// Backtraces of the running chip.
+pub type Backtrace<M = TargetMemory> = Unwinder<M>;
+
+/// The memory of the running chip.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TargetMemory;
+
+// The stack pointer of a sane frame is in DRAM, at least 16 bytes above its start.
+unsafe impl StackMemory for TargetMemory {
+    fn is_sane(&self, frame: &BacktraceFrame) -> bool {
+        frame.is_sane()
+    }
+
+    unsafe fn read_u32(&self, addr: u32) -> u32 {
+        core::ptr::read_volatile(addr as *const u32)
+    }
 }
 
 impl Backtrace {
@@ -131,69 +383,128 @@ impl Backtrace {
     /// - Setup PC and SP of function A (i.e. start of the stack's backtrace)
     /// - Setup PC of function B in `next_pc`
     pub fn new() -> Backtrace {
-        let mut frame = Backtrace {
-            pc: 0,
-            sp: 0,
-            next_pc: 0,
-            last: false,
-        };
+        let (mut pc, mut sp, mut next_pc) = (0, 0, 0);
         unsafe {
             super::esp_backtrace_get_start(
-                &mut frame.pc as *mut _,
-                &mut frame.sp as *mut _,
-                &mut frame.next_pc as *mut _,
+                &mut pc as *mut _,
+                &mut sp as *mut _,
+                &mut next_pc as *mut _,
             );
         }
 
-        frame
+        Unwinder::with_memory(TargetMemory, pc, sp, next_pc)
     }
 
-    /// Convert the PC register value to its true address
+    /// Create a backtrace of a task that isn't running.
     ///
-    /// The address of the current instruction is not stored as an exact u32
-    /// representation in PC register. This function will convert the value stored in the
-    /// PC register to a u32 address.
+    /// When a task is switched out, the port saves its context on the task's stack and
+    /// stores the address in `pxTopOfStack`, the first field of the TCB. This is an
+    /// `XtSolFrame` if the task yielded (flagged by a zero `exit` field), or an
+    /// `XtExcFrame` if it was interrupted. Both start the unwind from their PC, A1
+    /// (stack pointer) and A0 (return address).
     ///
-    /// Ported from `esp-idf/components/soc/include/soc/cpu.h`
-    pub fn get_real_pc(&self) -> u32 {
-        let mut pc = self.pc;
-        if (pc & 0x80000000) > 0 {
-            pc = (pc & 0x3fffffff) | 0x40000000;
+    /// # Safety
+    /// `task` must be a valid task that isn't running on any core, and it must not be
+    /// switched in or deleted while the backtrace is used, e.g. by suspending the
+    /// scheduler with `vTaskSuspendAll`.
+    pub unsafe fn for_task(task: TaskHandle_t) -> Backtrace {
+        let top_of_stack = *(task as *const *const u32);
+
+        if *top_of_stack == 0 {
+            let frame = &*(top_of_stack as *const XtSolFrame);
+            Unwinder::with_memory(
+                TargetMemory,
+                frame.pc as u32,
+                frame.a1 as u32,
+                frame.a0 as u32,
+            )
+        } else {
+            Backtrace::from_exception_frame(&*(top_of_stack as *const XtExcFrame))
         }
-        pc.saturating_sub(3)
     }
 
-    /// Get the previous stack frame from the current stack pointer
-    pub fn next_stack_frame(&mut self) {
-        // Use frame(i-1)'s BS area located below frame(i)'s sp to get frame(i-1)'s sp and frame(i-2)'s pc
-        unsafe {
-            let base_save = self.sp as *const c_void; // Base save area consists of 4 words under SP
-            self.pc = self.next_pc;
-            self.next_pc = *(base_save.sub(16) as *const u32); //If next_pc = 0, indicates frame(i-1) is the last frame on the stack
-            self.sp = *(base_save.sub(12) as *const u32);
-        }
+    /// Create a backtrace of the code that raised an exception or was interrupted, from
+    /// the `XtExcFrame` the exception vectors saved on the stack.
+    ///
+    /// Windows below the exception frame must have been spilled to the stack, which the
+    /// vectors do before dispatching to a handler.
+    pub fn from_exception_frame(frame: &XtExcFrame) -> Backtrace {
+        Unwinder::with_memory(TargetMemory, frame.pc, frame.a1, frame.a0)
     }
 }
 
-impl core::iter::Iterator for Backtrace {
-    type Item = BacktraceFrame;
+/// Write the name, state and backtrace of every task to `w`, one line per task.
+///
+/// The scheduler is suspended while the tasks are walked, so `w` must not block.
+/// Tasks that are running on the other core can't be unwound and are printed without a
+/// backtrace. The kernel only reports all tasks at once, so with more than
+/// [`MAX_DUMPED_TASKS`] tasks only their number is printed.
+pub fn dump_all_tasks(w: &mut dyn fmt::Write) -> fmt::Result {
+    let mut statuses = [core::mem::MaybeUninit::<TaskStatus_t>::uninit(); MAX_DUMPED_TASKS];
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.last {
-            return None;
+    unsafe { vTaskSuspendAll() };
+    let result = unsafe {
+        let total = uxTaskGetNumberOfTasks() as usize;
+        if total > MAX_DUMPED_TASKS {
+            writeln!(
+                w,
+                "{} tasks, more than the {} that can be dumped",
+                total, MAX_DUMPED_TASKS
+            )
+        } else {
+            let count = uxTaskGetSystemState(
+                statuses.as_mut_ptr() as *mut TaskStatus_t,
+                MAX_DUMPED_TASKS as _,
+                core::ptr::null_mut(),
+            ) as usize;
+            let statuses =
+                core::slice::from_raw_parts(statuses.as_ptr() as *const TaskStatus_t, count);
+            dump_tasks(w, statuses)
         }
+    };
+    unsafe { xTaskResumeAll() };
 
-        let res = BacktraceFrame {
-            pc: self.get_real_pc(),
-            sp: self.sp,
-        };
+    result
+}
+
+/// Write the tasks of `statuses`, the scheduler must be suspended.
+unsafe fn dump_tasks(w: &mut dyn fmt::Write, statuses: &[TaskStatus_t]) -> fmt::Result {
+    let current = xTaskGetCurrentTaskHandle();
+
+    for status in statuses {
+        write!(
+            w,
+            "{} ({}, stack high water mark {}):",
+            Task::from_raw(status.xHandle).name(),
+            state_name(status.eCurrentState),
+            status.usStackHighWaterMark
+        )?;
 
-        if res.is_sane() {
-            self.next_stack_frame();
+        let backtrace = if status.xHandle == current {
+            Backtrace::new()
+        } else if status.eCurrentState == eTaskState_eRunning {
+            writeln!(w, " running on the other core")?;
+            continue;
         } else {
-            self.last = true;
+            Backtrace::for_task(status.xHandle)
+        };
+
+        for frame in backtrace {
+            write!(w, " {}", frame)?;
         }
+        writeln!(w)?;
+    }
+
+    Ok(())
+}
 
-        Some(res)
+fn state_name(state: eTaskState) -> &'static str {
+    match state {
+        eTaskState_eRunning => "running",
+        eTaskState_eReady => "ready",
+        eTaskState_eBlocked => "blocked",
+        eTaskState_eSuspended => "suspended",
+        eTaskState_eDeleted => "deleted",
+        _ => "invalid",
     }
 }
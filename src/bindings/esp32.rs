@@ -1,6 +1,5 @@
 /* automatically generated by rust-bindgen 0.58.1 */
 
-pub const configNUM_CORES: u32 = 2;
 pub const XT_USE_THREAD_SAFE_CLIB: u32 = 0;
 pub const portUSING_MPU_WRAPPERS: u32 = 0;
 pub const configUSE_MUTEX: u32 = 1;
@@ -1567,22 +1566,14 @@ pub const XT_USER_SIZE: u32 = 1024;
 pub const configUSE_PREEMPTION: u32 = 1;
 pub const configUSE_IDLE_HOOK: u32 = 0;
 pub const configUSE_TICK_HOOK: u32 = 0;
-pub const configTICK_RATE_HZ: u32 = 100;
-pub const configMAX_PRIORITIES: u32 = 25;
-pub const configMINIMAL_STACK_SIZE: u32 = 768;
-pub const configIDLE_TASK_STACK_SIZE: u32 = 768;
-pub const configISR_STACK_SIZE: u32 = 1536;
-pub const configMAX_TASK_NAME_LEN: u32 = 16;
 pub const configUSE_TRACE_FACILITY_2: u32 = 0;
 pub const configBENCHMARK: u32 = 0;
 pub const configUSE_16_BIT_TICKS: u32 = 0;
 pub const configIDLE_SHOULD_YIELD: u32 = 0;
-pub const configQUEUE_REGISTRY_SIZE: u32 = 0;
 pub const configUSE_MUTEXES: u32 = 1;
 pub const configUSE_RECURSIVE_MUTEXES: u32 = 1;
 pub const configUSE_COUNTING_SEMAPHORES: u32 = 1;
 pub const CONFIG_FREERTOS_CHECK_STACKOVERFLOW_CANARY: u32 = 1;
-pub const configCHECK_FOR_STACK_OVERFLOW: u32 = 2;
 pub const configUSE_CO_ROUTINES: u32 = 0;
 pub const configMAX_CO_ROUTINE_PRIORITIES: u32 = 2;
 pub const INCLUDE_vTaskPrioritySet: u32 = 1;
@@ -1600,11 +1591,7 @@ pub const INCLUDE_xSemaphoreGetMutexHolder: u32 = 1;
 pub const configKERNEL_INTERRUPT_PRIORITY: u32 = 1;
 pub const configMAX_SYSCALL_INTERRUPT_PRIORITY: u32 = 3;
 pub const configSUPPORT_DYNAMIC_ALLOCATION: u32 = 1;
-pub const configSUPPORT_STATIC_ALLOCATION: u32 = 0;
 pub const configUSE_TIMERS: u32 = 1;
-pub const configTIMER_TASK_PRIORITY: u32 = 1;
-pub const configTIMER_QUEUE_LENGTH: u32 = 10;
-pub const configTIMER_TASK_STACK_DEPTH: u32 = 1024;
 pub const INCLUDE_xTimerPendFunctionCall: u32 = 1;
 pub const INCLUDE_eTaskGetState: u32 = 1;
 pub const configUSE_QUEUE_SETS: u32 = 1;
@@ -1768,7 +1755,7 @@ pub const INCLUDE_xTaskDelayUntil: u32 = 1;
 pub const INCLUDE_xTaskAbortDelay: u32 = 0;
 pub const INCLUDE_xQueueGetMutexHolder: u32 = 0;
 pub const INCLUDE_xTaskGetHandle: u32 = 0;
-pub const INCLUDE_uxTaskGetStackHighWaterMark2: u32 = 0;
+pub const INCLUDE_uxTaskGetStackHighWaterMark2: u32 = 1;
 pub const INCLUDE_xTaskResumeFromISR: u32 = 1;
 pub const INCLUDE_xTaskGetSchedulerState: u32 = 0;
 pub const INCLUDE_xTaskGetCurrentTaskHandle: u32 = 0;
@@ -1786,10 +1773,8 @@ pub const configEXPECTED_IDLE_TIME_BEFORE_SLEEP: u32 = 2;
 pub const configUSE_TIME_SLICING: u32 = 1;
 pub const configINCLUDE_APPLICATION_DEFINED_PRIVILEGED_FUNCTIONS: u32 = 0;
 pub const configUSE_STATS_FORMATTING_FUNCTIONS: u32 = 0;
-pub const configUSE_TRACE_FACILITY: u32 = 0;
 pub const configAPPLICATION_ALLOCATED_HEAP: u32 = 0;
 pub const configUSE_TASK_NOTIFICATIONS: u32 = 1;
-pub const configTASK_NOTIFICATION_ARRAY_ENTRIES: u32 = 1;
 pub const configUSE_POSIX_ERRNO: u32 = 0;
 pub const configSTACK_ALLOCATION_FROM_SEPARATE_HEAP: u32 = 0;
 pub const configINITIAL_TICK_COUNT: u32 = 0;
@@ -4485,6 +4470,7 @@ pub struct xSTATIC_TCB {
     pub ucDummy7: [u8; 16usize],
     pub uxDummy25: UBaseType_t,
     pub uxDummy9: UBaseType_t,
+    pub uxDummy10: [UBaseType_t; 2usize],
     pub uxDummy12: [UBaseType_t; 2usize],
     pub pvDummy15: [*mut chlorine::c_void; 1usize],
     pub ulDummy18: [u32; 1usize],
@@ -4495,7 +4481,7 @@ pub struct xSTATIC_TCB {
 fn bindgen_test_layout_xSTATIC_TCB() {
     assert_eq!(
         ::core::mem::size_of::<xSTATIC_TCB>(),
-        108usize,
+        116usize,
         concat!("Size of: ", stringify!(xSTATIC_TCB))
     );
     assert_eq!(
@@ -4594,8 +4580,18 @@ fn bindgen_test_layout_xSTATIC_TCB() {
         )
     );
     assert_eq!(
-        unsafe { &(*(::core::ptr::null::<xSTATIC_TCB>())).uxDummy12 as *const _ as usize },
+        unsafe { &(*(::core::ptr::null::<xSTATIC_TCB>())).uxDummy10 as *const _ as usize },
         88usize,
+        concat!(
+            "Offset of field: ",
+            stringify!(xSTATIC_TCB),
+            "::",
+            stringify!(uxDummy10)
+        )
+    );
+    assert_eq!(
+        unsafe { &(*(::core::ptr::null::<xSTATIC_TCB>())).uxDummy12 as *const _ as usize },
+        96usize,
         concat!(
             "Offset of field: ",
             stringify!(xSTATIC_TCB),
@@ -4605,7 +4601,7 @@ fn bindgen_test_layout_xSTATIC_TCB() {
     );
     assert_eq!(
         unsafe { &(*(::core::ptr::null::<xSTATIC_TCB>())).pvDummy15 as *const _ as usize },
-        96usize,
+        104usize,
         concat!(
             "Offset of field: ",
             stringify!(xSTATIC_TCB),
@@ -4615,7 +4611,7 @@ fn bindgen_test_layout_xSTATIC_TCB() {
     );
     assert_eq!(
         unsafe { &(*(::core::ptr::null::<xSTATIC_TCB>())).ulDummy18 as *const _ as usize },
-        100usize,
+        108usize,
         concat!(
             "Offset of field: ",
             stringify!(xSTATIC_TCB),
@@ -4625,7 +4621,7 @@ fn bindgen_test_layout_xSTATIC_TCB() {
     );
     assert_eq!(
         unsafe { &(*(::core::ptr::null::<xSTATIC_TCB>())).ucDummy19 as *const _ as usize },
-        104usize,
+        112usize,
         concat!(
             "Offset of field: ",
             stringify!(xSTATIC_TCB),
@@ -4635,7 +4631,7 @@ fn bindgen_test_layout_xSTATIC_TCB() {
     );
     assert_eq!(
         unsafe { &(*(::core::ptr::null::<xSTATIC_TCB>())).uxDummy20 as *const _ as usize },
-        105usize,
+        113usize,
         concat!(
             "Offset of field: ",
             stringify!(xSTATIC_TCB),
@@ -5489,6 +5485,17 @@ extern "C" {
         pxCreatedTask: *mut TaskHandle_t,
     ) -> BaseType_t;
 }
+extern "C" {
+    pub fn xTaskCreateStatic(
+        pxTaskCode: TaskFunction_t,
+        pcName: *const chlorine::c_char,
+        ulStackDepth: u32,
+        pvParameters: *mut chlorine::c_void,
+        uxPriority: UBaseType_t,
+        puxStackBuffer: *mut StackType_t,
+        pxTaskBuffer: *mut StaticTask_t,
+    ) -> TaskHandle_t;
+}
 extern "C" {
     pub fn xTaskCreateRestricted(
         pxTaskDefinition: *const TaskParameters_t,
@@ -6370,6 +6377,13 @@ extern "C" {
     #[doc = " xTask was created."]
     pub fn uxTaskGetStackHighWaterMark2(xTask: TaskHandle_t) -> u16;
 }
+extern "C" {
+    #[doc = " Returns the lowest address of the stack of the task \\p xTask."]
+    #[doc = ""]
+    #[doc = " INCLUDE_pxTaskGetStackStart must be set to 1 in FreeRTOSConfig.h for"]
+    #[doc = " this function to be available."]
+    pub fn pxTaskGetStackStart(xTask: TaskHandle_t) -> *mut u8;
+}
 extern "C" {
     pub fn vTaskSetThreadLocalStoragePointer(
         xTaskToSet: TaskHandle_t,
@@ -6395,6 +6409,20 @@ extern "C" {
     #[doc = " @param pcTaskName A character string containing the name of the offending task."]
     pub fn vApplicationStackOverflowHook(xTask: TaskHandle_t, pcTaskName: *mut chlorine::c_char);
 }
+extern "C" {
+    pub fn vApplicationGetIdleTaskMemory(
+        ppxIdleTaskTCBBuffer: *mut *mut StaticTask_t,
+        ppxIdleTaskStackBuffer: *mut *mut StackType_t,
+        pulIdleTaskStackSize: *mut u32,
+    );
+}
+extern "C" {
+    pub fn vApplicationGetTimerTaskMemory(
+        ppxTimerTaskTCBBuffer: *mut *mut StaticTask_t,
+        ppxTimerTaskStackBuffer: *mut *mut StackType_t,
+        pulTimerTaskStackSize: *mut u32,
+    );
+}
 extern "C" {
     #[doc = " task.h"]
     #[doc = " <pre>"]
@@ -7536,6 +7564,16 @@ extern "C" {
         pxCallbackFunction: TimerCallbackFunction_t,
     ) -> TimerHandle_t;
 }
+extern "C" {
+    pub fn xTimerCreateStatic(
+        pcTimerName: *const chlorine::c_char,
+        xTimerPeriodInTicks: TickType_t,
+        uxAutoReload: UBaseType_t,
+        pvTimerID: *mut chlorine::c_void,
+        pxCallbackFunction: TimerCallbackFunction_t,
+        pxTimerBuffer: *mut StaticTimer_t,
+    ) -> TimerHandle_t;
+}
 extern "C" {
     #[doc = " void *pvTimerGetTimerID( TimerHandle_t xTimer );"]
     #[doc = ""]
@@ -8481,6 +8519,15 @@ extern "C" {
         ucQueueType: u8,
     ) -> QueueHandle_t;
 }
+extern "C" {
+    pub fn xQueueGenericCreateStatic(
+        uxQueueLength: UBaseType_t,
+        uxItemSize: UBaseType_t,
+        pucQueueStorage: *mut u8,
+        pxStaticQueue: *mut StaticQueue_t,
+        ucQueueType: u8,
+    ) -> QueueHandle_t;
+}
 extern "C" {
     pub fn xQueueCreateSet(uxEventQueueLength: UBaseType_t) -> QueueSetHandle_t;
 }
@@ -8543,6 +8590,10 @@ pub type EventBits_t = TickType_t;
 extern "C" {
     pub fn xEventGroupCreate() -> EventGroupHandle_t;
 }
+extern "C" {
+    pub fn xEventGroupCreateStatic(pxEventGroupBuffer: *mut StaticEventGroup_t)
+        -> EventGroupHandle_t;
+}
 extern "C" {
     #[doc = " event_groups.h"]
     #[doc = " <pre>"]
@@ -8942,6 +8993,121 @@ extern "C" {
 extern "C" {
     pub fn vEventGroupClearBitsCallback(pvEventGroup: *mut chlorine::c_void, ulBitsToClear: u32);
 }
+#[doc = " Type by which stream buffers are referenced.  For example, a call to"]
+#[doc = " xStreamBufferCreate() returns an StreamBufferHandle_t variable that can"]
+#[doc = " then be used as a parameter to xStreamBufferSend(), xStreamBufferReceive(),"]
+#[doc = " etc."]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct StreamBufferDef_t {
+    _unused: [u8; 0],
+}
+pub type StreamBufferHandle_t = *mut StreamBufferDef_t;
+extern "C" {
+    pub fn xStreamBufferSend(
+        xStreamBuffer: StreamBufferHandle_t,
+        pvTxData: *const chlorine::c_void,
+        xDataLengthBytes: usize,
+        xTicksToWait: TickType_t,
+    ) -> usize;
+}
+extern "C" {
+    pub fn xStreamBufferSendFromISR(
+        xStreamBuffer: StreamBufferHandle_t,
+        pvTxData: *const chlorine::c_void,
+        xDataLengthBytes: usize,
+        pxHigherPriorityTaskWoken: *mut BaseType_t,
+    ) -> usize;
+}
+extern "C" {
+    pub fn xStreamBufferReceive(
+        xStreamBuffer: StreamBufferHandle_t,
+        pvRxData: *mut chlorine::c_void,
+        xBufferLengthBytes: usize,
+        xTicksToWait: TickType_t,
+    ) -> usize;
+}
+extern "C" {
+    pub fn xStreamBufferReceiveFromISR(
+        xStreamBuffer: StreamBufferHandle_t,
+        pvRxData: *mut chlorine::c_void,
+        xBufferLengthBytes: usize,
+        pxHigherPriorityTaskWoken: *mut BaseType_t,
+    ) -> usize;
+}
+extern "C" {
+    pub fn vStreamBufferDelete(xStreamBuffer: StreamBufferHandle_t);
+}
+extern "C" {
+    pub fn xStreamBufferIsFull(xStreamBuffer: StreamBufferHandle_t) -> BaseType_t;
+}
+extern "C" {
+    pub fn xStreamBufferIsEmpty(xStreamBuffer: StreamBufferHandle_t) -> BaseType_t;
+}
+extern "C" {
+    pub fn xStreamBufferReset(xStreamBuffer: StreamBufferHandle_t) -> BaseType_t;
+}
+extern "C" {
+    pub fn xStreamBufferSpacesAvailable(xStreamBuffer: StreamBufferHandle_t) -> usize;
+}
+extern "C" {
+    pub fn xStreamBufferBytesAvailable(xStreamBuffer: StreamBufferHandle_t) -> usize;
+}
+extern "C" {
+    pub fn xStreamBufferSetTriggerLevel(
+        xStreamBuffer: StreamBufferHandle_t,
+        xTriggerLevel: usize,
+    ) -> BaseType_t;
+}
+extern "C" {
+    pub fn xStreamBufferSendCompletedFromISR(
+        xStreamBuffer: StreamBufferHandle_t,
+        pxHigherPriorityTaskWoken: *mut BaseType_t,
+    ) -> BaseType_t;
+}
+extern "C" {
+    pub fn xStreamBufferReceiveCompletedFromISR(
+        xStreamBuffer: StreamBufferHandle_t,
+        pxHigherPriorityTaskWoken: *mut BaseType_t,
+    ) -> BaseType_t;
+}
+extern "C" {
+    pub fn xStreamBufferGenericCreate(
+        xBufferSizeBytes: usize,
+        xTriggerLevelBytes: usize,
+        xIsMessageBuffer: BaseType_t,
+    ) -> StreamBufferHandle_t;
+}
+extern "C" {
+    pub fn xStreamBufferGenericCreateStatic(
+        xBufferSizeBytes: usize,
+        xTriggerLevelBytes: usize,
+        xIsMessageBuffer: BaseType_t,
+        pucStreamBufferStorageArea: *mut u8,
+        pxStaticStreamBuffer: *mut StaticStreamBuffer_t,
+    ) -> StreamBufferHandle_t;
+}
+extern "C" {
+    pub fn xStreamBufferNextMessageLengthBytes(xStreamBuffer: StreamBufferHandle_t) -> usize;
+}
+extern "C" {
+    pub fn uxStreamBufferGetStreamBufferNumber(xStreamBuffer: StreamBufferHandle_t)
+        -> UBaseType_t;
+}
+extern "C" {
+    pub fn vStreamBufferSetStreamBufferNumber(
+        xStreamBuffer: StreamBufferHandle_t,
+        uxStreamBufferNumber: UBaseType_t,
+    );
+}
+extern "C" {
+    pub fn ucStreamBufferGetStreamBufferType(xStreamBuffer: StreamBufferHandle_t) -> u8;
+}
+#[doc = " Type by which message buffers are referenced.  For example, a call to"]
+#[doc = " xMessageBufferCreate() returns an MessageBufferHandle_t variable that can"]
+#[doc = " then be used as a parameter to xMessageBufferSend(), xMessageBufferReceive(),"]
+#[doc = " etc."]
+pub type MessageBufferHandle_t = *mut chlorine::c_void;
 extern "C" {
     #[doc = " Get the first frame of the current stack's backtrace"]
     #[doc = ""]
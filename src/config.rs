@@ -0,0 +1,7 @@
+//! The kernel configuration the crate was built with.
+//!
+//! `FreeRTOSConfig.h` is generated by the build script from the cargo features and the
+//! `FREERTOS_*` environment variables (see the README); these are the same values, so
+//! Rust code and the kernel agree on e.g. `configTICK_RATE_HZ`.
+
+include!(concat!(env!("OUT_DIR"), "/config.rs"));
@@ -0,0 +1,291 @@
+//! Post-mortem core dumps of all tasks.
+//!
+//! [`write_core_dump`] serializes the state of every task into a compact binary format
+//! and writes it to a [`CoreDumpSink`], e.g. a flash partition, from a panic handler.
+//! The `freertos-esp32-coredump` host tool in the `coredump` directory converts a dump
+//! into an ELF core file that can be loaded into GDB together with the firmware.
+//!
+//! # Format
+//!
+//! All integers are little-endian and `u32` unless noted otherwise, all records are a
+//! multiple of 4 bytes long. The dump starts with a header:
+//!
+//! | Size | Field |
+//! |------|-------|
+//! | 4    | [`MAGIC`] |
+//! | 2    | [`VERSION`] (`u16`) |
+//! | 2    | number of task records (`u16`) |
+//! | 4    | the core that wrote the dump |
+//! | 4    | number of tasks, more than the task records if the dump is truncated |
+//! | 28   | heap size, used, peak, free, minimum ever free, allocations and frees, zero without a heap |
+//!
+//! followed by one record per task, the task that was running on the dumping core
+//! first:
+//!
+//! | Size | Field |
+//! |------|-------|
+//! | 4    | task handle (address of the TCB) |
+//! | 16   | task name, padded with zeros |
+//! | 4    | task state (`eTaskState`) |
+//! | 4    | current priority |
+//! | 4    | [`RegisterSource`] |
+//! | 4    | lowest address of the stack |
+//! | 96   | PC, PS, A0 to A15, SAR, EXCCAUSE, EXCVADDR, LBEG, LEND, LCOUNT; zero if not saved or not present on the chip |
+//! | 4    | length of the TCB |
+//! | n    | the TCB |
+//! | 4    | address of the dumped stack memory |
+//! | 4    | length of the dumped stack memory |
+//! | n    | the stack memory |
+//!
+//! As the kernel doesn't record where a stack ends, up to [`MAX_STACK_DUMP`] bytes above
+//! the saved stack pointer are dumped. The kernel only reports all tasks at once, so with
+//! more than [`MAX_TASKS`] tasks the dump is truncated to the task that wrote it.
+
+use core::mem::{self, MaybeUninit};
+use core::slice;
+
+use crate::backtrace::{SOC_DRAM_HIGH, SOC_DRAM_LOW};
+use crate::glue::portGET_CORE_ID;
+use crate::{
+    eTaskState_eInvalid, eTaskState_eRunning, esp_backtrace_get_start, pdFALSE, strlen,
+    uxTaskGetNumberOfTasks, uxTaskGetSystemState, vTaskGetInfo, vTaskSuspendAll,
+    xTaskGetCurrentTaskHandle, xTaskResumeAll, StaticTask_t, TaskHandle_t, TaskStatus_t,
+    XtExcFrame, XtSolFrame,
+};
+
+/// The first bytes of a core dump.
+pub const MAGIC: [u8; 4] = *b"FRCD";
+/// The version of the format, incremented on every incompatible change.
+pub const VERSION: u16 = 1;
+/// The maximum number of tasks in a core dump.
+pub const MAX_TASKS: usize = 32;
+/// The maximum number of stack bytes dumped per task.
+pub const MAX_STACK_DUMP: usize = 4096;
+
+/// The length of the task name in a task record, independent of `configMAX_TASK_NAME_LEN`.
+const NAME_LEN: usize = 16;
+/// The number of registers in a task record.
+const REGISTER_COUNT: usize = 24;
+const PC: usize = 0;
+const PS: usize = 1;
+/// `A0`, `A1` (the stack pointer) etc. follow at `A0 + n`.
+const A0: usize = 2;
+const SAR: usize = 18;
+const EXCCAUSE: usize = 19;
+const EXCVADDR: usize = 20;
+#[cfg(not(feature = "esp32s2"))]
+const LBEG: usize = 21;
+#[cfg(not(feature = "esp32s2"))]
+const LEND: usize = 22;
+#[cfg(not(feature = "esp32s2"))]
+const LCOUNT: usize = 23;
+
+/// Where the registers of a task record come from.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegisterSource {
+    /// The task is running on the other core, no registers or stack are dumped.
+    None = 0,
+    /// The task wrote the dump: PC and A1 of the function that called
+    /// [`write_core_dump`], A0 is the return address into its caller.
+    Current = 1,
+    /// The task yielded: PC, PS and A0 to A3 of the `XtSolFrame`.
+    Solicited = 2,
+    /// The task was interrupted: all registers of the `XtExcFrame`.
+    Exception = 3,
+}
+
+/// The destination of a core dump, e.g. a flash partition or a UART.
+pub trait CoreDumpSink {
+    /// The error returned by [`write`](CoreDumpSink::write).
+    type Error;
+
+    /// Append `data` to the dump.
+    fn write(&mut self, data: &[u8]) -> Result<(), Self::Error>;
+}
+
+/// Write a core dump of all tasks to `sink`.
+///
+/// Must be called from a task, e.g. in a panic handler, as the scheduler is suspended
+/// while the tasks are dumped. It can't be called from an ISR, a critical section or an
+/// [`AssertHandler`](crate::hooks::AssertHandler), which may run in either: suspending
+/// the scheduler there fails a kernel assert. If there are more than [`MAX_TASKS`] tasks, only
+/// the calling task is dumped and the header records the number of tasks.
+#[inline(never)]
+pub fn write_core_dump<S: CoreDumpSink>(sink: &mut S) -> Result<(), S::Error> {
+    let mut start = Start::default();
+    unsafe { esp_backtrace_get_start(&mut start.pc, &mut start.sp, &mut start.next_pc) };
+    let mut statuses = [MaybeUninit::<TaskStatus_t>::uninit(); MAX_TASKS];
+
+    unsafe { vTaskSuspendAll() };
+    let result = unsafe {
+        let total = uxTaskGetNumberOfTasks() as usize;
+        let count = if total > MAX_TASKS {
+            vTaskGetInfo(
+                xTaskGetCurrentTaskHandle(),
+                statuses.as_mut_ptr() as *mut TaskStatus_t,
+                pdFALSE,
+                eTaskState_eInvalid,
+            );
+            1
+        } else {
+            uxTaskGetSystemState(
+                statuses.as_mut_ptr() as *mut TaskStatus_t,
+                MAX_TASKS as _,
+                core::ptr::null_mut(),
+            ) as usize
+        };
+        let statuses = slice::from_raw_parts_mut(statuses.as_mut_ptr() as *mut TaskStatus_t, count);
+        write_tasks(sink, statuses, total, &start)
+    };
+    unsafe { xTaskResumeAll() };
+
+    result
+}
+
+/// The registers of the caller of [`write_core_dump`], see `esp_backtrace_get_start`.
+#[derive(Default)]
+struct Start {
+    pc: u32,
+    sp: u32,
+    next_pc: u32,
+}
+
+/// Write the header and the tasks of `statuses` out of `total`, the scheduler must be
+/// suspended.
+unsafe fn write_tasks<S: CoreDumpSink>(
+    sink: &mut S,
+    statuses: &mut [TaskStatus_t],
+    total: usize,
+    start: &Start,
+) -> Result<(), S::Error> {
+    let current = xTaskGetCurrentTaskHandle();
+    if let Some(i) = statuses.iter().position(|s| s.xHandle == current) {
+        statuses.swap(0, i);
+    }
+
+    sink.write(&MAGIC)?;
+    sink.write(&VERSION.to_le_bytes())?;
+    sink.write(&(statuses.len() as u16).to_le_bytes())?;
+    write_u32(sink, portGET_CORE_ID())?;
+    write_u32(sink, total as u32)?;
+    for &value in heap_stats().iter() {
+        write_u32(sink, value)?;
+    }
+
+    for status in statuses.iter() {
+        write_task(sink, status, current, start)?;
+    }
+
+    Ok(())
+}
+
+unsafe fn write_task<S: CoreDumpSink>(
+    sink: &mut S,
+    status: &TaskStatus_t,
+    current: TaskHandle_t,
+    start: &Start,
+) -> Result<(), S::Error> {
+    let mut registers = [0; REGISTER_COUNT];
+    let (source, stack) = if status.xHandle == current {
+        let sp = start.sp;
+        registers[PC] = start.pc;
+        registers[A0] = start.next_pc;
+        registers[A0 + 1] = sp;
+        // Include the base save area below the stack pointer.
+        (RegisterSource::Current, sp.wrapping_sub(16))
+    } else if status.eCurrentState == eTaskState_eRunning {
+        (RegisterSource::None, 0)
+    } else {
+        // See `Backtrace::for_task`.
+        let top_of_stack = *(status.xHandle as *const *const u32);
+        if *top_of_stack == 0 {
+            let frame = &*(top_of_stack as *const XtSolFrame);
+            registers[PC] = frame.pc as u32;
+            registers[PS] = frame.ps as u32;
+            registers[A0] = frame.a0 as u32;
+            registers[A0 + 1] = frame.a1 as u32;
+            registers[A0 + 2] = frame.a2 as u32;
+            registers[A0 + 3] = frame.a3 as u32;
+            (RegisterSource::Solicited, top_of_stack as u32)
+        } else {
+            let frame = &*(top_of_stack as *const XtExcFrame);
+            registers[PC] = frame.pc;
+            registers[PS] = frame.ps;
+            registers[A0..A0 + 16].copy_from_slice(&[
+                frame.a0, frame.a1, frame.a2, frame.a3, frame.a4, frame.a5, frame.a6, frame.a7,
+                frame.a8, frame.a9, frame.a10, frame.a11, frame.a12, frame.a13, frame.a14,
+                frame.a15,
+            ]);
+            registers[SAR] = frame.sar;
+            registers[EXCCAUSE] = frame.exccause;
+            registers[EXCVADDR] = frame.excvaddr;
+            #[cfg(not(feature = "esp32s2"))]
+            {
+                registers[LBEG] = frame.lbeg;
+                registers[LEND] = frame.lend;
+                registers[LCOUNT] = frame.lcount;
+            }
+            (RegisterSource::Exception, top_of_stack as u32)
+        }
+    };
+
+    let mut name = [0; NAME_LEN];
+    let name_len = (strlen(status.pcTaskName) as usize).min(name.len());
+    name[..name_len].copy_from_slice(slice::from_raw_parts(
+        status.pcTaskName as *const u8,
+        name_len,
+    ));
+
+    write_u32(sink, status.xHandle as u32)?;
+    sink.write(&name)?;
+    write_u32(sink, status.eCurrentState as u32)?;
+    write_u32(sink, status.uxCurrentPriority as u32)?;
+    write_u32(sink, source as u32)?;
+    write_u32(sink, status.pxStackBase as u32)?;
+    for &register in registers.iter() {
+        write_u32(sink, register)?;
+    }
+
+    let tcb_len = mem::size_of::<StaticTask_t>() & !3;
+    write_u32(sink, tcb_len as u32)?;
+    sink.write(slice::from_raw_parts(status.xHandle as *const u8, tcb_len))?;
+
+    let stack_in_dram = (SOC_DRAM_LOW..SOC_DRAM_HIGH).contains(&(stack as usize));
+    let (stack, stack_len) = if source != RegisterSource::None && stack_in_dram {
+        let stack = stack & !3;
+        (stack, MAX_STACK_DUMP.min(SOC_DRAM_HIGH - stack as usize))
+    } else {
+        (0, 0)
+    };
+    write_u32(sink, stack)?;
+    write_u32(sink, stack_len as u32)?;
+    if stack_len == 0 {
+        sink.write(&[])
+    } else {
+        sink.write(slice::from_raw_parts(stack as *const u8, stack_len))
+    }
+}
+
+fn write_u32<S: CoreDumpSink>(sink: &mut S, value: u32) -> Result<(), S::Error> {
+    sink.write(&value.to_le_bytes())
+}
+
+#[cfg(any(feature = "use-rust-alloc", feature = "heap-4", feature = "heap-5"))]
+fn heap_stats() -> [u32; 7] {
+    let s = crate::heap::heap_stats();
+    [
+        s.size as u32,
+        s.used as u32,
+        s.peak as u32,
+        s.free as u32,
+        s.min_ever_free as u32,
+        s.allocations as u32,
+        s.frees as u32,
+    ]
+}
+
+#[cfg(not(any(feature = "use-rust-alloc", feature = "heap-4", feature = "heap-5")))]
+fn heap_stats() -> [u32; 7] {
+    [0; 7]
+}
@@ -0,0 +1,25 @@
+//! The timer daemon task, which processes the timer commands and the function calls
+//! deferred with `xTimerPendFunctionCall`.
+
+use crate::ticks::Ticks;
+use crate::{
+    taskSCHEDULER_NOT_STARTED, taskSCHEDULER_RUNNING, xTaskGetCurrentTaskHandle,
+    xTaskGetSchedulerState, xTimerGetTimerDaemonTaskHandle,
+};
+
+/// How long the caller may block to send to the timer command queue.
+///
+/// The timer daemon can't wait for space in its own queue and nothing may block while
+/// the scheduler is suspended, so these get [`Ticks::ZERO`]. Returns `None` before the
+/// scheduler was started, when the queue may not exist yet.
+pub(crate) fn timer_queue_timeout() -> Option<Ticks> {
+    match unsafe { xTaskGetSchedulerState() } {
+        taskSCHEDULER_NOT_STARTED => None,
+        taskSCHEDULER_RUNNING
+            if unsafe { xTimerGetTimerDaemonTaskHandle() != xTaskGetCurrentTaskHandle() } =>
+        {
+            Some(Ticks::MAX)
+        }
+        _ => Some(Ticks::ZERO),
+    }
+}
@@ -0,0 +1,43 @@
+//! Errors returned by the safe kernel object wrappers.
+
+/// An error returned by a FreeRTOS API call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The kernel could not allocate the memory required for the object.
+    OutOfMemory,
+    /// The queue was full.
+    QueueFull,
+    /// The queue was empty.
+    QueueEmpty,
+    /// The operation did not complete within the given timeout.
+    Timeout,
+    /// The task notification couldn't be overwritten, as it is still pending.
+    NotificationPending,
+    /// The queue or semaphore is not empty, already part of a queue set or (when
+    /// removing it) not part of the set.
+    QueueSetMember,
+    /// The provided buffer is too small for the received message.
+    BufferTooSmall,
+    /// The static memory of the object is already in use.
+    InUse,
+    /// An argument is outside of the range the kernel accepts, e.g. a zero size.
+    InvalidArgument,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::OutOfMemory => write!(f, "could not allocate required memory"),
+            Error::QueueFull => write!(f, "queue is full"),
+            Error::QueueEmpty => write!(f, "queue is empty"),
+            Error::Timeout => write!(f, "timed out"),
+            Error::NotificationPending => write!(f, "task notification is still pending"),
+            Error::QueueSetMember => {
+                write!(f, "queue set member is not empty or in the wrong set")
+            }
+            Error::BufferTooSmall => write!(f, "buffer is too small for the message"),
+            Error::InUse => write!(f, "static object is already in use"),
+            Error::InvalidArgument => write!(f, "argument is out of range"),
+        }
+    }
+}
@@ -0,0 +1,339 @@
+//! Event groups with typed event bits.
+
+use core::marker::PhantomData;
+
+use chlorine::c_void;
+
+use crate::daemon::timer_queue_timeout;
+use crate::error::Error;
+use crate::ticks::Ticks;
+use crate::{
+    pdFALSE, pdPASS, pdTRUE, vEventGroupClearBitsCallback, vEventGroupDelete,
+    vEventGroupSetBitsCallback, xEventGroupClearBits, xEventGroupCreate, xEventGroupGetBitsFromISR,
+    xEventGroupSetBits, xEventGroupSync, xEventGroupWaitBits, xTimerPendFunctionCall,
+    xTimerPendFunctionCallFromISR, BaseType_t, EventBits_t, EventGroupHandle_t, PendedFunction_t,
+};
+
+#[cfg(feature = "static-alloc")]
+pub use static_impl::*;
+
+/// The bits of an [`EventBits_t`] that can be used for events.
+///
+/// The upper 8 bits are reserved by the kernel.
+pub const EVENT_BITS_MASK: EventBits_t = 0x00ff_ffff;
+
+/// A set of event bits, e.g. a type generated by the `bitflags` crate.
+pub trait EventBits: Copy {
+    /// Get the raw bits.
+    fn bits(&self) -> EventBits_t;
+    /// Create the set from raw bits, ignoring bits that don't correspond to a flag.
+    fn from_bits_truncate(bits: EventBits_t) -> Self;
+}
+
+impl EventBits for EventBits_t {
+    fn bits(&self) -> EventBits_t {
+        *self
+    }
+
+    fn from_bits_truncate(bits: EventBits_t) -> Self {
+        bits
+    }
+}
+
+/// A FreeRTOS event group whose bits are represented by `B`.
+///
+/// The `*_from_isr` variants never block and additionally return whether a higher
+/// priority task was woken, in which case the ISR should yield (see
+/// [`vPortYieldFromISR`]) before it returns.
+///
+/// Dropping the group deletes it from the timer daemon task, after any calls pended by
+/// [`EventGroup::set_from_isr`] and [`EventGroup::clear_from_isr`] ran. If the timer
+/// command queue is full and the caller can't wait for it (i.e. it is the daemon itself,
+/// or the scheduler is suspended), the group is leaked instead.
+///
+/// [`vPortYieldFromISR`]: crate::vPortYieldFromISR
+pub struct EventGroup<B: EventBits = EventBits_t> {
+    handle: EventGroupHandle_t,
+    _marker: PhantomData<B>,
+}
+
+unsafe impl<B: EventBits> Send for EventGroup<B> {}
+unsafe impl<B: EventBits> Sync for EventGroup<B> {}
+
+impl<B: EventBits> EventGroup<B> {
+    /// Create a new event group with all bits cleared.
+    pub fn new() -> Result<EventGroup<B>, Error> {
+        let handle = unsafe { xEventGroupCreate() };
+        if handle.is_null() {
+            return Err(Error::OutOfMemory);
+        }
+
+        Ok(EventGroup {
+            handle,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Get the raw FreeRTOS event group handle.
+    pub fn raw(&self) -> EventGroupHandle_t {
+        self.handle
+    }
+
+    /// Set `bits`, returning the bits of the group when the call returned.
+    ///
+    /// Tasks waiting on the group may clear bits before this returns.
+    pub fn set(&self, bits: B) -> B {
+        B::from_bits_truncate(unsafe { xEventGroupSetBits(self.handle, raw_bits(bits)) })
+    }
+
+    /// Clear `bits`, returning the bits of the group before they were cleared.
+    pub fn clear(&self, bits: B) -> B {
+        B::from_bits_truncate(unsafe { xEventGroupClearBits(self.handle, raw_bits(bits)) })
+    }
+
+    /// Get the current bits of the group.
+    pub fn get(&self) -> B {
+        // Same as the `xEventGroupGetBits` macro.
+        B::from_bits_truncate(unsafe { xEventGroupClearBits(self.handle, 0) })
+    }
+
+    /// Get the current bits of the group from an ISR.
+    pub fn get_from_isr(&self) -> B {
+        B::from_bits_truncate(unsafe { xEventGroupGetBitsFromISR(self.handle) })
+    }
+
+    /// Wait up to `timeout` for any of `bits` to be set.
+    ///
+    /// Returns the bits of the group when the condition was met. If `clear_on_exit` is
+    /// set, `bits` are cleared before returning (but are still included in the result).
+    pub fn wait_any(&self, bits: B, clear_on_exit: bool, timeout: Ticks) -> Result<B, Error> {
+        let bits = raw_bits(bits);
+        let res = self.wait_bits(bits, clear_on_exit, false, timeout);
+        if res & bits != 0 {
+            Ok(B::from_bits_truncate(res))
+        } else {
+            Err(Error::Timeout)
+        }
+    }
+
+    /// Wait up to `timeout` for all of `bits` to be set.
+    ///
+    /// Returns the bits of the group when the condition was met. If `clear_on_exit` is
+    /// set, `bits` are cleared before returning (but are still included in the result).
+    pub fn wait_all(&self, bits: B, clear_on_exit: bool, timeout: Ticks) -> Result<B, Error> {
+        let bits = raw_bits(bits);
+        let res = self.wait_bits(bits, clear_on_exit, true, timeout);
+        if res & bits == bits {
+            Ok(B::from_bits_truncate(res))
+        } else {
+            Err(Error::Timeout)
+        }
+    }
+
+    /// Atomically set `set` and wait up to `timeout` for all of `wait_for` to be set.
+    ///
+    /// This is a rendezvous between tasks: each task sets its own bit and waits for the
+    /// bits of all other tasks. The `wait_for` bits are cleared once all tasks arrived.
+    /// Returns the bits of the group when the condition was met.
+    pub fn sync(&self, set: B, wait_for: B, timeout: Ticks) -> Result<B, Error> {
+        let wait_for = raw_bits(wait_for);
+        let res = unsafe { xEventGroupSync(self.handle, raw_bits(set), wait_for, timeout.raw()) };
+        if res & wait_for == wait_for {
+            Ok(B::from_bits_truncate(res))
+        } else {
+            Err(Error::Timeout)
+        }
+    }
+
+    /// Set `bits` from an ISR.
+    ///
+    /// Setting bits is not a deterministic operation, so the request is deferred to the
+    /// timer daemon task (like the `xEventGroupSetBitsFromISR` macro). Fails with
+    /// [`Error::QueueFull`] if the timer command queue is full.
+    ///
+    /// Returns whether a higher priority task (i.e. the timer daemon) was woken.
+    pub fn set_from_isr(&self, bits: B) -> Result<bool, Error> {
+        self.pend_from_isr(Some(vEventGroupSetBitsCallback), raw_bits(bits))
+    }
+
+    /// Clear `bits` from an ISR.
+    ///
+    /// Like [`EventGroup::set_from_isr`] the request is deferred to the timer daemon
+    /// task.
+    ///
+    /// Returns whether a higher priority task (i.e. the timer daemon) was woken.
+    pub fn clear_from_isr(&self, bits: B) -> Result<bool, Error> {
+        self.pend_from_isr(Some(vEventGroupClearBitsCallback), raw_bits(bits))
+    }
+
+    fn wait_bits(
+        &self,
+        bits: EventBits_t,
+        clear_on_exit: bool,
+        wait_all: bool,
+        timeout: Ticks,
+    ) -> EventBits_t {
+        unsafe {
+            xEventGroupWaitBits(
+                self.handle,
+                bits,
+                if clear_on_exit { pdTRUE } else { pdFALSE },
+                if wait_all { pdTRUE } else { pdFALSE },
+                timeout.raw(),
+            )
+        }
+    }
+
+    fn pend_from_isr(&self, func: PendedFunction_t, bits: EventBits_t) -> Result<bool, Error> {
+        let mut woken: BaseType_t = pdFALSE;
+        let res = unsafe {
+            xTimerPendFunctionCallFromISR(
+                func,
+                self.handle as *mut c_void,
+                bits,
+                &mut woken as *mut _,
+            )
+        };
+
+        if res == pdPASS {
+            Ok(woken != pdFALSE)
+        } else {
+            Err(Error::QueueFull)
+        }
+    }
+}
+
+unsafe extern "C" fn delete_event_group(group: *mut c_void, _: u32) {
+    vEventGroupDelete(group as EventGroupHandle_t);
+}
+
+impl<B: EventBits> Drop for EventGroup<B> {
+    fn drop(&mut self) {
+        // Calls pended from an ISR still reference the group, so it is deleted by a call
+        // that is queued after them.
+        let timeout = match timer_queue_timeout() {
+            Some(timeout) => timeout,
+            // The command queue usually doesn't exist yet (sending would assert), so ISRs
+            // can't have pended calls unless a timer was created this early.
+            None => return unsafe { vEventGroupDelete(self.handle) },
+        };
+        unsafe {
+            xTimerPendFunctionCall(
+                Some(delete_event_group),
+                self.handle as *mut c_void,
+                0,
+                timeout.raw(),
+            );
+        }
+    }
+}
+
+fn raw_bits<B: EventBits>(bits: B) -> EventBits_t {
+    let bits = bits.bits();
+    debug_assert!(
+        bits & !EVENT_BITS_MASK == 0,
+        "the upper 8 event bits are reserved"
+    );
+    bits & EVENT_BITS_MASK
+}
+
+#[cfg(feature = "static-alloc")]
+mod static_impl {
+    use core::cell::UnsafeCell;
+    use core::marker::PhantomData;
+    use core::mem::MaybeUninit;
+
+    use super::{EventBits, EventGroup};
+    use crate::atomic::Once;
+    use crate::{xEventGroupCreateStatic, EventBits_t, StaticEventGroup_t};
+
+    /// An [`EventGroup`] whose memory is part of the `static` item it is placed in.
+    ///
+    /// The event group is created on the first call to [`StaticEventGroup::get`].
+    pub struct StaticEventGroup<B: EventBits = EventBits_t> {
+        buffer: UnsafeCell<MaybeUninit<StaticEventGroup_t>>,
+        group: Once<EventGroup<B>>,
+    }
+
+    unsafe impl<B: EventBits> Sync for StaticEventGroup<B> {}
+
+    impl<B: EventBits> Default for StaticEventGroup<B> {
+        fn default() -> Self {
+            StaticEventGroup::new()
+        }
+    }
+
+    impl<B: EventBits> StaticEventGroup<B> {
+        /// Create the uninitialized event group memory.
+        pub const fn new() -> StaticEventGroup<B> {
+            StaticEventGroup {
+                buffer: UnsafeCell::new(MaybeUninit::uninit()),
+                group: Once::new(),
+            }
+        }
+
+        /// Get the event group with all bits cleared, creating it on the first call.
+        pub fn get(&'static self) -> &'static EventGroup<B> {
+            self.group.call_once(|| EventGroup {
+                // Can't fail, as all memory is provided.
+                handle: unsafe {
+                    xEventGroupCreateStatic(self.buffer.get() as *mut StaticEventGroup_t)
+                },
+                _marker: PhantomData,
+            })
+        }
+    }
+}
+
+#[cfg(all(test, feature = "host-sim", feature = "use-rust-alloc"))]
+mod tests {
+    extern crate std;
+    use std::sync::Arc;
+
+    use super::EventGroup;
+    use crate::error::Error;
+    use crate::sim;
+    use crate::task::spawn;
+    use crate::ticks::Ticks;
+
+    #[test]
+    fn wait_for_bits_of_another_task() {
+        sim::run(|| {
+            let group = Arc::new(EventGroup::new().unwrap());
+
+            let setter = group.clone();
+            let handle = spawn(move || {
+                setter.set(0b001);
+                setter.set(0b100);
+            })
+            .unwrap();
+            assert_eq!(group.wait_all(0b101, true, Ticks::MAX), Ok(0b101));
+            handle.join();
+
+            assert_eq!(group.get(), 0);
+            assert_eq!(
+                group.wait_any(0b110, false, Ticks::new(1)),
+                Err(Error::Timeout)
+            );
+            group.set(0b010);
+            assert_eq!(group.wait_any(0b110, false, Ticks::ZERO), Ok(0b010));
+            assert_eq!(group.clear(0b010), 0b010);
+        });
+    }
+
+    #[test]
+    fn sync_two_tasks() {
+        sim::run(|| {
+            let group = Arc::new(EventGroup::new().unwrap());
+
+            let other = group.clone();
+            let handle = spawn(move || other.sync(0b10, 0b11, Ticks::MAX)).unwrap();
+            assert_eq!(
+                group.sync(0b01, 0b11, Ticks::MAX).map(|b| b & 0b11),
+                Ok(0b11)
+            );
+            assert_eq!(handle.join().map(|b| b & 0b11), Ok(0b11));
+            assert_eq!(group.get(), 0);
+        });
+    }
+}
@@ -1,11 +1,18 @@
-use core::{
-    sync::atomic::{AtomicI32, Ordering},
-};
+use core::ptr;
 
-use chlorine::c_char;
-use spin::mutex::{SpinMutex, SpinMutexGuard};
+use chlorine::{c_char, c_void};
 
-use crate::{vTaskEnterCritical, vTaskExitCritical, UBaseType_t};
+#[cfg(not(feature = "host-sim"))]
+use crate::backtrace::Backtrace;
+use crate::hooks;
+use crate::task::Task;
+
+#[cfg(feature = "host-sim")]
+pub use host_impl::*;
+#[cfg(feature = "use-rust-alloc")]
+pub use malloc_impl::pvPortMallocAligned;
+#[cfg(not(feature = "host-sim"))]
+pub use xtensa_impl::*;
 
 #[cfg(feature = "use-rust-alloc")]
 mod malloc_impl {
@@ -14,35 +21,178 @@ mod malloc_impl {
 
     use chlorine::c_void;
 
+    use crate::{heap, portBYTE_ALIGNMENT};
+
+    /// Stored right in front of every allocated block, so that `vPortFree` can
+    /// reconstruct the layout of the whole allocation.
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Header {
+        /// Size of the allocation, including the header and padding.
+        size: usize,
+        /// Alignment of the allocation and the returned block.
+        align: usize,
+    }
+
+    impl Header {
+        /// Offset of the returned block from the start of the allocation: the header
+        /// size rounded up to `align`.
+        const fn offset(align: usize) -> usize {
+            (mem::size_of::<Header>() + align - 1) & !(align - 1)
+        }
+
+        /// The layout of an allocation with a block of `size` bytes aligned to `align`.
+        ///
+        /// Returns `None` if `align` isn't a power of two or the size overflows.
+        fn layout(size: usize, align: usize) -> Option<Layout> {
+            if !align.is_power_of_two() {
+                return None;
+            }
+
+            let align = align.max(mem::align_of::<Header>());
+            let size = Header::offset(align).checked_add(size)?;
+            Layout::from_size_align(size, align).ok()
+        }
+
+        /// Write the header of the allocation `base` with `layout` and return its block.
+        unsafe fn write(base: *mut u8, layout: Layout) -> *mut u8 {
+            let block = base.add(Header::offset(layout.align()));
+            (block as *mut Header).sub(1).write(Header {
+                size: layout.size(),
+                align: layout.align(),
+            });
+            block
+        }
+
+        /// Read the header in front of `block`, returning the start and layout of its
+        /// allocation.
+        unsafe fn read(block: *mut u8) -> (*mut u8, Layout) {
+            let header = (block as *const Header).sub(1).read();
+            (
+                block.sub(Header::offset(header.align)),
+                Layout::from_size_align_unchecked(header.size, header.align),
+            )
+        }
+    }
+
     #[no_mangle]
     unsafe extern "C" fn vPortFree(ptr: *mut c_void) {
-        if ptr != core::ptr::null_mut() {
-            let ptr = ptr.sub(mem::size_of::<usize>());
-            // get block size
-            let size: usize = *mem::transmute::<_, *mut usize>(ptr);
-
-            ::alloc::alloc::dealloc(ptr as *mut u8, Layout::from_size_align_unchecked(size, 4));
+        if !ptr.is_null() {
+            let (base, layout) = Header::read(ptr as *mut u8);
+            ::alloc::alloc::dealloc(base, layout);
+            heap::record_free(layout.size());
         }
     }
 
     #[no_mangle]
     unsafe extern "C" fn pvPortMalloc(wanted_size: usize) -> *mut c_void {
+        pvPortMallocAligned(wanted_size, portBYTE_ALIGNMENT as usize)
+    }
+
+    /// Allocate a block of `wanted_size` bytes aligned to `alignment`, which must be a
+    /// power of two. Returns null if the allocation failed.
+    ///
+    /// # Safety
+    /// The block must only be freed with `vPortFree`.
+    #[no_mangle]
+    pub unsafe extern "C" fn pvPortMallocAligned(
+        wanted_size: usize,
+        alignment: usize,
+    ) -> *mut c_void {
         if wanted_size == 0 {
             return core::ptr::null_mut();
         }
 
-        let layout = Layout::from_size_align(wanted_size + mem::size_of::<usize>(), 4).unwrap();
+        let layout = match Header::layout(wanted_size, alignment) {
+            Some(layout) => layout,
+            None => return core::ptr::null_mut(),
+        };
 
-        let ptr = ::alloc::alloc::alloc(layout);
-        if ptr == core::ptr::null_mut() {
+        let base = ::alloc::alloc::alloc(layout);
+        if base.is_null() {
             return core::ptr::null_mut();
         }
 
-        // set first usize to `layout.size()`
-        let ptr_usize: *mut usize = mem::transmute(ptr);
-        *ptr_usize = layout.size();
+        heap::record_alloc(layout.size());
+        Header::write(base, layout) as *mut c_void
+    }
 
-        mem::transmute(ptr.add(mem::size_of::<usize>()))
+    #[cfg(test)]
+    mod tests {
+        use super::Header;
+
+        extern crate std;
+        use std::alloc::{alloc, dealloc};
+
+        #[test]
+        fn header_round_trip() {
+            for &align in &[1, 4, 8, 16, 64] {
+                for &size in &[1, 3, 32, 100] {
+                    let layout = Header::layout(size, align).unwrap();
+                    unsafe {
+                        let base = alloc(layout);
+                        let block = Header::write(base, layout);
+                        assert_eq!(block as usize % align, 0);
+                        assert!(block as usize + size <= base as usize + layout.size());
+
+                        let (read_base, read_layout) = Header::read(block);
+                        assert_eq!(read_base, base);
+                        assert_eq!(read_layout, layout);
+                        dealloc(base, layout);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn invalid_layouts() {
+            assert_eq!(Header::layout(16, 0), None);
+            assert_eq!(Header::layout(16, 12), None);
+            assert_eq!(Header::layout(usize::MAX, 4), None);
+        }
+    }
+}
+
+#[cfg(feature = "static-alloc")]
+mod static_alloc_impl {
+    use core::mem::MaybeUninit;
+    use core::ptr;
+
+    use crate::{
+        configIDLE_TASK_STACK_SIZE, configTIMER_TASK_STACK_DEPTH, StackType_t, StaticTask_t,
+    };
+
+    const IDLE_STACK_SIZE: usize = configIDLE_TASK_STACK_SIZE as usize;
+    const TIMER_STACK_SIZE: usize = configTIMER_TASK_STACK_DEPTH as usize;
+
+    // Only handed out once to the kernel, which owns them from then on.
+    static mut IDLE_TASK_TCB: MaybeUninit<StaticTask_t> = MaybeUninit::uninit();
+    static mut IDLE_TASK_STACK: [StackType_t; IDLE_STACK_SIZE] = [0; IDLE_STACK_SIZE];
+    static mut TIMER_TASK_TCB: MaybeUninit<StaticTask_t> = MaybeUninit::uninit();
+    static mut TIMER_TASK_STACK: [StackType_t; TIMER_STACK_SIZE] = [0; TIMER_STACK_SIZE];
+
+    /// Provide the memory of the idle task of core 0, the kernel allocates the idle
+    /// tasks of the other cores itself.
+    #[no_mangle]
+    unsafe extern "C" fn vApplicationGetIdleTaskMemory(
+        tcb: *mut *mut StaticTask_t,
+        stack: *mut *mut StackType_t,
+        stack_size: *mut u32,
+    ) {
+        *tcb = ptr::addr_of_mut!(IDLE_TASK_TCB) as *mut StaticTask_t;
+        *stack = ptr::addr_of_mut!(IDLE_TASK_STACK) as *mut StackType_t;
+        *stack_size = IDLE_STACK_SIZE as u32;
+    }
+
+    #[no_mangle]
+    unsafe extern "C" fn vApplicationGetTimerTaskMemory(
+        tcb: *mut *mut StaticTask_t,
+        stack: *mut *mut StackType_t,
+        stack_size: *mut u32,
+    ) {
+        *tcb = ptr::addr_of_mut!(TIMER_TASK_TCB) as *mut StaticTask_t;
+        *stack = ptr::addr_of_mut!(TIMER_TASK_STACK) as *mut StackType_t;
+        *stack_size = TIMER_STACK_SIZE as u32;
     }
 }
 
@@ -64,14 +214,38 @@ unsafe extern "C" fn vPortPanic(
     } else {
         core::str::from_utf8_unchecked(core::slice::from_raw_parts(func as *const u8, func_len))
     };
-    panic!("{}:{} ({}) - RTOS assert failed!", file, line, func);
+
+    let task = crate::xTaskGetCurrentTaskHandle();
+    let info = hooks::AssertInfo {
+        file,
+        line,
+        function: func,
+        task: if task.is_null() {
+            None
+        } else {
+            Some(Task::from_raw(task))
+        },
+        core_id: portGET_CORE_ID(),
+    };
+    hooks::call_assert_handler(&info);
+
+    #[cfg(feature = "assert-abort")]
+    hooks::restart();
+
+    #[cfg(not(any(feature = "assert-abort", feature = "assert-continue")))]
+    panic!("{}", info);
 }
 
 #[no_mangle]
 unsafe extern "C" fn vApplicationStackOverflowHook(
-    _task: super::TaskHandle_t,
+    task: super::TaskHandle_t,
     task_name: *mut c_char,
 ) {
+    // Only the context switch and the frames right below it, the rest of the stack is
+    // likely corrupted.
+    #[cfg(not(feature = "host-sim"))]
+    let backtrace = Backtrace::new().max_depth(8);
+
     let len = super::strlen(task_name);
     let task_name = if len == 0 {
         ""
@@ -82,110 +256,269 @@ unsafe extern "C" fn vApplicationStackOverflowHook(
         ))
     };
 
-    panic!("StackOverflow in task '{}'", task_name);
+    let report = hooks::StackOverflowReport {
+        task: Task::from_raw(task),
+        task_name,
+        stack_start: super::pxTaskGetStackStart(task) as usize,
+        stack_high_water_mark: super::uxTaskGetStackHighWaterMark2(task) as usize,
+        core_id: portGET_CORE_ID(),
+        #[cfg(not(feature = "host-sim"))]
+        backtrace,
+    };
+
+    match hooks::call_stack_overflow_handler(&report) {
+        hooks::StackOverflowAction::Panic => panic!("{}", report),
+        hooks::StackOverflowAction::Suspend => {
+            // The kernel is locked inside the context switch, so the timer daemon
+            // suspends the task once the switch is done.
+            let res = super::xTimerPendFunctionCallFromISR(
+                Some(suspend_overflowed_task),
+                task as *mut c_void,
+                0,
+                ptr::null_mut(),
+            );
+            assert!(res == super::pdPASS, "{} (timer command queue full)", report);
+        }
+        hooks::StackOverflowAction::Restart => hooks::restart(),
+    }
 }
 
-#[inline]
-pub unsafe fn vPortYieldFromISR() {
-    crate::_frxt_setup_switch()
+/// Suspend the task that overflowed its stack, run by the timer daemon.
+unsafe extern "C" fn suspend_overflowed_task(task: *mut c_void, _: u32) {
+    super::vTaskSuspend(task as super::TaskHandle_t);
 }
 
-#[inline]
-pub unsafe fn ulTaskEnterCriticalFromISR() -> UBaseType_t {
-    let mut state: UBaseType_t;
-    llvm_asm!("rsr.ps $0" : "=a"(state) :: "memory");
-    vTaskEnterCritical();
+#[cfg(not(feature = "host-sim"))]
+mod xtensa_impl {
+    use crate::{vTaskEnterCritical, vTaskExitCritical, UBaseType_t};
 
-    state
-}
+    pub use lock_impl::*;
 
-#[inline]
-pub unsafe fn vTaskExitCriticalFromISR(previous_state: UBaseType_t) {
-    vTaskExitCritical();
-    llvm_asm!("wsr.ps $0" :: "r"(previous_state) : "memory")
-}
+    #[inline]
+    pub unsafe fn vPortYieldFromISR() {
+        crate::_frxt_setup_switch()
+    }
+
+    #[inline]
+    pub unsafe fn ulTaskEnterCriticalFromISR() -> UBaseType_t {
+        let mut state: UBaseType_t;
+        llvm_asm!("rsr.ps $0" : "=a"(state) :: "memory");
+        vTaskEnterCritical();
 
-#[inline]
-pub fn portGET_CORE_ID() -> UBaseType_t {
-    let mut id: u32;
-    unsafe {
-        llvm_asm!(r#"rsr.prid $0;
-              extui $0, $0, 13, 1"# : "=r"(id));
+        state
     }
-    id
-}
 
-static ISR_LOCK: (SpinMutex<usize>, AtomicI32) = (SpinMutex::new(0), AtomicI32::new(-1));
-static TASK_LOCK: (SpinMutex<usize>, AtomicI32) = (SpinMutex::new(0), AtomicI32::new(-1));
+    #[inline]
+    pub unsafe fn vTaskExitCriticalFromISR(previous_state: UBaseType_t) {
+        vTaskExitCritical();
+        llvm_asm!("wsr.ps $0" :: "r"(previous_state) : "memory")
+    }
 
-#[link_section = ".rwtext"]
-pub fn take_lock_recursive((lock, owner): &(SpinMutex<usize>, AtomicI32)) {
-    let core_id = portGET_CORE_ID() as i32;
+    #[inline]
+    pub fn portGET_CORE_ID() -> UBaseType_t {
+        let mut id: u32;
+        unsafe {
+            llvm_asm!(r#"rsr.prid $0;
+                  extui $0, $0, 13, 1"# : "=r"(id));
+        }
+        id
+    }
 
-    let w = if owner.load(Ordering::Relaxed) == core_id {
-        // Safe because we checked that this core already owns the lock.
-        let w = unsafe { &mut *lock.as_mut_ptr() };
+    /// Raise the interrupt level to `portINTLEVEL_HIGHINT` (5) and return the previous `PS`.
+    #[inline]
+    pub unsafe fn portDISABLE_INTERRUPTS() -> UBaseType_t {
+        let state: UBaseType_t;
+        llvm_asm!("rsil $0, 5" : "=a"(state) :: "memory" : "volatile");
+        state
+    }
 
-        w
-    } else {
-        // Wait for the other core to unlock the mutex.
-        let l = lock.lock();
-        owner.store(core_id, Ordering::Relaxed);
+    /// Lower the interrupt level to 0 and return the previous `PS`.
+    #[inline]
+    pub unsafe fn portENABLE_INTERRUPTS() -> UBaseType_t {
+        let state: UBaseType_t;
+        llvm_asm!("rsil $0, 0" : "=a"(state) :: "memory" : "volatile");
+        state
+    }
 
-        // Keep the mutex locked, it will be unlocked in `give_lock_recursive`.
-        SpinMutexGuard::leak(l)
-    };
+    /// Restore the `PS` returned by [`portDISABLE_INTERRUPTS`].
+    #[inline]
+    pub unsafe fn portRESTORE_INTERRUPTS(state: UBaseType_t) {
+        llvm_asm!("wsr.ps $0" :: "r"(state) : "memory" : "volatile")
+    }
 
-    // Increment the lock count.
-    *w += 1;
-}
+    #[inline]
+    pub fn portNOP() {
+        unsafe { llvm_asm!("nop" :::: "volatile") }
+    }
 
-#[link_section = ".rwtext"]
-pub fn give_lock_recursive((lock, owner): &(SpinMutex<usize>, AtomicI32)) {
-    let core_id = portGET_CORE_ID() as i32;
+    #[cfg(not(feature = "esp32s2"))]
+    mod lock_impl {
+        use core::sync::atomic::{AtomicI32, Ordering};
 
-    // The current core should always own the lock on a call to this function.
-    debug_assert!(owner.load(Ordering::Relaxed) == core_id);
+        use spin::mutex::{SpinMutex, SpinMutexGuard};
 
-    // Safe because this core owns the lock (see assert above).
-    let w = unsafe { &mut *lock.as_mut_ptr() };
-    let count = *w - 1;
-    *w = count;
+        use super::portGET_CORE_ID;
 
-    // Unlock the mutex because the count is zero.
-    if count == 0 {
-        // `-1` signals an unowned lock.
-        // Note this MUST happen before the lock is unlocked, otherwise it causes a race
-        // condition in `take_lock_recursive()`.
-        owner.store(-1, Ordering::Relaxed);
+        pub(super) static ISR_LOCK: (SpinMutex<usize>, AtomicI32) =
+            (SpinMutex::new(0), AtomicI32::new(-1));
+        pub(super) static TASK_LOCK: (SpinMutex<usize>, AtomicI32) =
+            (SpinMutex::new(0), AtomicI32::new(-1));
 
-        // Safe because this core owns the lock and the lock count is 0.
-        unsafe {
-            lock.force_unlock();
+        #[link_section = ".rwtext"]
+        pub fn take_lock_recursive((lock, owner): &(SpinMutex<usize>, AtomicI32)) {
+            let core_id = portGET_CORE_ID() as i32;
+
+            let w = if owner.load(Ordering::Relaxed) == core_id {
+                // Safe because we checked that this core already owns the lock.
+                let w = unsafe { &mut *lock.as_mut_ptr() };
+
+                w
+            } else {
+                // Wait for the other core to unlock the mutex.
+                let l = lock.lock();
+                owner.store(core_id, Ordering::Relaxed);
+
+                // Keep the mutex locked, it will be unlocked in `give_lock_recursive`.
+                SpinMutexGuard::leak(l)
+            };
+
+            // Increment the lock count.
+            *w += 1;
+        }
+
+        #[link_section = ".rwtext"]
+        pub fn give_lock_recursive((lock, owner): &(SpinMutex<usize>, AtomicI32)) {
+            let core_id = portGET_CORE_ID() as i32;
+
+            // The current core should always own the lock on a call to this function.
+            debug_assert!(owner.load(Ordering::Relaxed) == core_id);
+
+            // Safe because this core owns the lock (see assert above).
+            let w = unsafe { &mut *lock.as_mut_ptr() };
+            let count = *w - 1;
+            *w = count;
+
+            // Unlock the mutex because the count is zero.
+            if count == 0 {
+                // `-1` signals an unowned lock.
+                // Note this MUST happen before the lock is unlocked, otherwise it causes a race
+                // condition in `take_lock_recursive()`.
+                owner.store(-1, Ordering::Relaxed);
+
+                // Safe because this core owns the lock and the lock count is 0.
+                unsafe {
+                    lock.force_unlock();
+                }
+            }
         }
     }
-}
 
-#[no_mangle]
-#[link_section = ".rwtext"]
-pub extern "C" fn vPortTakeISRLock() {
-    take_lock_recursive(&ISR_LOCK);
-}
+    /// The ESP32-S2 has a single core and no compare-and-set instruction to build a
+    /// spinlock with, so its locks only count how deeply they are nested. The kernel
+    /// takes them with interrupts masked.
+    #[cfg(feature = "esp32s2")]
+    mod lock_impl {
+        use core::sync::atomic::AtomicUsize;
 
-#[no_mangle]
-#[link_section = ".rwtext"]
-pub extern "C" fn vPortGiveISRLock() {
-    give_lock_recursive(&ISR_LOCK);
-}
+        use crate::atomic;
 
-#[no_mangle]
-#[link_section = ".rwtext"]
-pub extern "C" fn vPortTakeTaskLock() {
-    take_lock_recursive(&TASK_LOCK);
+        pub(super) static ISR_LOCK: AtomicUsize = AtomicUsize::new(0);
+        pub(super) static TASK_LOCK: AtomicUsize = AtomicUsize::new(0);
+
+        #[link_section = ".rwtext"]
+        pub fn take_lock_recursive(count: &AtomicUsize) {
+            atomic::fetch_add(count, 1);
+        }
+
+        #[link_section = ".rwtext"]
+        pub fn give_lock_recursive(count: &AtomicUsize) {
+            let prev = atomic::fetch_sub(count, 1);
+            debug_assert!(prev > 0);
+        }
+    }
+
+    #[no_mangle]
+    #[link_section = ".rwtext"]
+    pub extern "C" fn vPortTakeISRLock() {
+        take_lock_recursive(&ISR_LOCK);
+    }
+
+    #[no_mangle]
+    #[link_section = ".rwtext"]
+    pub extern "C" fn vPortGiveISRLock() {
+        give_lock_recursive(&ISR_LOCK);
+    }
+
+    #[no_mangle]
+    #[link_section = ".rwtext"]
+    pub extern "C" fn vPortTakeTaskLock() {
+        take_lock_recursive(&TASK_LOCK);
+    }
+
+    #[no_mangle]
+    #[link_section = ".rwtext"]
+    pub extern "C" fn vPortGiveTaskLock() {
+        give_lock_recursive(&TASK_LOCK);
+    }
 }
 
-#[no_mangle]
-#[link_section = ".rwtext"]
-pub extern "C" fn vPortGiveTaskLock() {
-    give_lock_recursive(&TASK_LOCK);
+/// The POSIX port of the `host-sim` mode runs a single core, its interrupts are signals
+/// and its task and ISR locks are the kernel's single core defaults.
+#[cfg(feature = "host-sim")]
+// The safety requirements are the ones of the C API.
+#[allow(clippy::missing_safety_doc)]
+mod host_impl {
+    use crate::{
+        vPortClearInterruptMask, vPortEnableInterrupts, vPortYield, xPortSetInterruptMask,
+        UBaseType_t,
+    };
+
+    /// The POSIX port has no separate yield for interrupts.
+    #[inline]
+    pub unsafe fn vPortYieldFromISR() {
+        vPortYield()
+    }
+
+    #[inline]
+    pub unsafe fn ulTaskEnterCriticalFromISR() -> UBaseType_t {
+        xPortSetInterruptMask()
+    }
+
+    #[inline]
+    pub unsafe fn vTaskExitCriticalFromISR(previous_state: UBaseType_t) {
+        vPortClearInterruptMask(previous_state)
+    }
+
+    #[inline]
+    pub fn portGET_CORE_ID() -> UBaseType_t {
+        0
+    }
+
+    /// Set the interrupt mask of the port and return the previous one for
+    /// [`portRESTORE_INTERRUPTS`].
+    ///
+    /// The POSIX port doesn't report whether the signals that simulate the interrupts were
+    /// blocked, so they are only blocked by `taskDISABLE_INTERRUPTS` and critical sections,
+    /// which can be undone without it.
+    #[inline]
+    pub unsafe fn portDISABLE_INTERRUPTS() -> UBaseType_t {
+        xPortSetInterruptMask()
+    }
+
+    /// Unblock the signals that simulate the interrupts, returns 0 as the POSIX port
+    /// doesn't report whether they were blocked.
+    #[inline]
+    pub unsafe fn portENABLE_INTERRUPTS() -> UBaseType_t {
+        vPortEnableInterrupts();
+        0
+    }
+
+    /// Restore the interrupt mask returned by [`portDISABLE_INTERRUPTS`].
+    #[inline]
+    pub unsafe fn portRESTORE_INTERRUPTS(state: UBaseType_t) {
+        vPortClearInterruptMask(state)
+    }
+
+    #[inline]
+    pub fn portNOP() {}
 }
@@ -0,0 +1,289 @@
+//! Heap statistics and the allocator glue between Rust and the kernel.
+//!
+//! With `use-rust-alloc`, the kernel allocates from the Rust global allocator and only
+//! blocks allocated through `pvPortMalloc` (i.e. kernel objects and C code) are counted
+//! here. As the global allocator doesn't report its capacity, the size of the heap has
+//! to be set with `set_heap_size` for the free byte counts to be meaningful.
+//!
+//! With `heap-4` or `heap-5`, the kernel's own heap implementation is compiled and
+//! [`FreeRtosAllocator`] is registered as the global allocator, so that Rust and C
+//! share a single heap. `heap_5` starts without memory, [`init`] or [`define_regions`]
+//! must be called before the first allocation.
+
+#[cfg(any(feature = "heap-4", feature = "heap-5"))]
+pub use freertos_heap_impl::*;
+#[cfg(feature = "heap-5")]
+pub use heap_5_impl::*;
+#[cfg(feature = "use-rust-alloc")]
+pub use rust_alloc_impl::{heap_stats, set_heap_size};
+#[cfg(feature = "use-rust-alloc")]
+pub(crate) use rust_alloc_impl::{record_alloc, record_free};
+
+/// A snapshot of the kernel heap usage, see [`heap_stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HeapStats {
+    /// The size of the heap.
+    pub size: usize,
+    /// Bytes currently allocated, including block headers.
+    pub used: usize,
+    /// The maximum of `used` since boot.
+    pub peak: usize,
+    /// `size - used`, i.e. the remaining bytes.
+    pub free: usize,
+    /// `size - peak`, i.e. the low-water mark of `free`.
+    pub min_ever_free: usize,
+    /// Number of successful allocations.
+    pub allocations: usize,
+    /// Number of freed blocks.
+    pub frees: usize,
+}
+
+#[cfg(feature = "use-rust-alloc")]
+mod rust_alloc_impl {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::HeapStats;
+    use crate::atomic;
+    use crate::HeapStats_t;
+
+    static HEAP_SIZE: AtomicUsize = AtomicUsize::new(0);
+    static USED: AtomicUsize = AtomicUsize::new(0);
+    static PEAK: AtomicUsize = AtomicUsize::new(0);
+    static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+    static FREES: AtomicUsize = AtomicUsize::new(0);
+
+    /// Set the number of bytes available to the global allocator.
+    pub fn set_heap_size(size: usize) {
+        HEAP_SIZE.store(size, Ordering::Relaxed);
+    }
+
+    /// Get the current heap statistics.
+    pub fn heap_stats() -> HeapStats {
+        let size = HEAP_SIZE.load(Ordering::Relaxed);
+        let used = USED.load(Ordering::Relaxed);
+        let peak = PEAK.load(Ordering::Relaxed);
+
+        HeapStats {
+            size,
+            used,
+            peak,
+            free: size.saturating_sub(used),
+            min_ever_free: size.saturating_sub(peak),
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            frees: FREES.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Record an allocation of `size` bytes.
+    pub(crate) fn record_alloc(size: usize) {
+        let used = atomic::fetch_add(&USED, size) + size;
+        atomic::fetch_max(&PEAK, used);
+        atomic::fetch_add(&ALLOCATIONS, 1);
+    }
+
+    /// Record that a block of `size` bytes was freed.
+    pub(crate) fn record_free(size: usize) {
+        atomic::fetch_sub(&USED, size);
+        atomic::fetch_add(&FREES, 1);
+    }
+
+    #[no_mangle]
+    extern "C" fn xPortGetFreeHeapSize() -> usize {
+        heap_stats().free
+    }
+
+    #[no_mangle]
+    extern "C" fn xPortGetMinimumEverFreeHeapSize() -> usize {
+        heap_stats().min_ever_free
+    }
+
+    /// The free block fields are left zero, as the layout of the heap is up to the
+    /// global allocator.
+    #[no_mangle]
+    unsafe extern "C" fn vPortGetHeapStats(stats: *mut HeapStats_t) {
+        let s = heap_stats();
+        *stats = HeapStats_t {
+            xAvailableHeapSpaceInBytes: s.free,
+            xSizeOfLargestFreeBlockInBytes: 0,
+            xSizeOfSmallestFreeBlockInBytes: 0,
+            xNumberOfFreeBlocks: 0,
+            xMinimumEverFreeBytesRemaining: s.min_ever_free,
+            xNumberOfSuccessfulAllocations: s.allocations,
+            xNumberOfSuccessfulFrees: s.frees,
+        };
+    }
+}
+
+#[cfg(any(feature = "heap-4", feature = "heap-5"))]
+mod freertos_heap_impl {
+    use core::alloc::{GlobalAlloc, Layout};
+    use core::mem::{self, MaybeUninit};
+
+    use chlorine::c_void;
+
+    use super::HeapStats;
+    use crate::{portBYTE_ALIGNMENT, pvPortMalloc, vPortFree, vPortGetHeapStats, HeapStats_t};
+
+    /// A Rust global allocator that allocates from the kernel heap.
+    ///
+    /// Blocks with a larger alignment than `portBYTE_ALIGNMENT` are over-allocated and
+    /// store the pointer to the kernel block right in front of them.
+    pub struct FreeRtosAllocator;
+
+    unsafe impl GlobalAlloc for FreeRtosAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            if layout.align() <= portBYTE_ALIGNMENT as usize {
+                return pvPortMalloc(layout.size()) as *mut u8;
+            }
+
+            let size = match layout
+                .size()
+                .checked_add(layout.align() + mem::size_of::<usize>())
+            {
+                Some(size) => size,
+                None => return core::ptr::null_mut(),
+            };
+            let block = pvPortMalloc(size) as *mut u8;
+            if block.is_null() {
+                return block;
+            }
+
+            let offset = mem::size_of::<usize>() + layout.align() - 1;
+            let ptr = ((block as usize + offset) & !(layout.align() - 1)) as *mut u8;
+            (ptr as *mut *mut u8).sub(1).write(block);
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            let block = if layout.align() <= portBYTE_ALIGNMENT as usize {
+                ptr
+            } else {
+                (ptr as *mut *mut u8).sub(1).read()
+            };
+            vPortFree(block as *mut c_void);
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: FreeRtosAllocator = FreeRtosAllocator;
+
+    /// Get the current heap statistics.
+    pub fn heap_stats() -> HeapStats {
+        let mut stats = MaybeUninit::<HeapStats_t>::uninit();
+        let stats = unsafe {
+            vPortGetHeapStats(stats.as_mut_ptr());
+            stats.assume_init()
+        };
+        let size = heap_size();
+
+        HeapStats {
+            size,
+            used: size.saturating_sub(stats.xAvailableHeapSpaceInBytes),
+            peak: size.saturating_sub(stats.xMinimumEverFreeBytesRemaining),
+            free: stats.xAvailableHeapSpaceInBytes,
+            min_ever_free: stats.xMinimumEverFreeBytesRemaining,
+            allocations: stats.xNumberOfSuccessfulAllocations,
+            frees: stats.xNumberOfSuccessfulFrees,
+        }
+    }
+
+    #[cfg(feature = "heap-4")]
+    fn heap_size() -> usize {
+        crate::configTOTAL_HEAP_SIZE as usize
+    }
+
+    #[cfg(feature = "heap-5")]
+    use super::heap_5_impl::heap_size;
+}
+
+#[cfg(feature = "heap-5")]
+mod heap_5_impl {
+    use core::ops::Range;
+    use core::ptr;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::backtrace::{SOC_EXTRAM_DATA_HIGH, SOC_EXTRAM_DATA_LOW};
+    use crate::{vPortDefineHeapRegions, HeapRegion_t};
+
+    /// The total size of the regions, set by [`define_regions`].
+    static HEAP_SIZE: AtomicUsize = AtomicUsize::new(0);
+
+    /// The maximum number of regions passed to [`define_regions`].
+    pub const MAX_REGIONS: usize = 4;
+
+    /// The end of the internal DRAM usable as heap, the ROM keeps its data above it.
+    #[cfg(any(feature = "esp32", feature = "esp32s2"))]
+    pub const DRAM_HEAP_HIGH: usize = 0x3FFE_0000;
+    /// The end of the internal DRAM usable as heap, the ROM keeps its data above it.
+    #[cfg(feature = "esp32s3")]
+    pub const DRAM_HEAP_HIGH: usize = 0x3FCE_0000;
+
+    /// The address window of the external PSRAM on the data bus. The fitted PSRAM is
+    /// mapped from its start and is usually smaller than the window.
+    pub const PSRAM_WINDOW: Range<usize> = SOC_EXTRAM_DATA_LOW..SOC_EXTRAM_DATA_HIGH;
+
+    extern "C" {
+        /// The end of the static data in DRAM, defined by the linker script.
+        static _heap_start: u8;
+    }
+
+    pub(super) fn heap_size() -> usize {
+        HEAP_SIZE.load(Ordering::Relaxed)
+    }
+
+    /// The internal DRAM that isn't used by static data.
+    pub fn internal_dram_region() -> Range<usize> {
+        (unsafe { ptr::addr_of!(_heap_start) } as usize)..DRAM_HEAP_HIGH
+    }
+
+    /// Hand the internal DRAM and the first `psram_size` bytes of the external PSRAM to
+    /// the kernel heap, pass 0 if no PSRAM is fitted.
+    ///
+    /// Panics if `psram_size` is larger than [`PSRAM_WINDOW`].
+    ///
+    /// # Safety
+    /// Same as [`define_regions`], and the PSRAM must be initialized and at least
+    /// `psram_size` bytes large.
+    pub unsafe fn init(psram_size: usize) {
+        assert!(
+            psram_size <= PSRAM_WINDOW.end - PSRAM_WINDOW.start,
+            "the PSRAM is larger than its address window"
+        );
+        if psram_size > 0 {
+            let psram = PSRAM_WINDOW.start..PSRAM_WINDOW.start + psram_size;
+            define_regions(&[psram, internal_dram_region()]);
+        } else {
+            define_regions(&[internal_dram_region()]);
+        }
+    }
+
+    /// Hand up to [`MAX_REGIONS`] memory regions, sorted by address, to the kernel heap
+    /// (`vPortDefineHeapRegions`).
+    ///
+    /// # Safety
+    /// Must be called exactly once, before anything is allocated. The regions must not
+    /// overlap and must not be used by anything else.
+    pub unsafe fn define_regions(regions: &[Range<usize>]) {
+        assert!(regions.len() <= MAX_REGIONS, "too many heap regions");
+        assert!(
+            regions.windows(2).all(|w| w[0].end <= w[1].start),
+            "heap regions must be sorted and must not overlap"
+        );
+
+        // The list is terminated by a region with a size of zero.
+        let mut raw = [HeapRegion_t {
+            pucStartAddress: ptr::null_mut(),
+            xSizeInBytes: 0,
+        }; MAX_REGIONS + 1];
+        for (raw, region) in raw.iter_mut().zip(regions) {
+            raw.pucStartAddress = region.start as *mut u8;
+            raw.xSizeInBytes = region.end - region.start;
+        }
+
+        HEAP_SIZE.store(
+            regions.iter().map(|r| r.end - r.start).sum(),
+            Ordering::Relaxed,
+        );
+        vPortDefineHeapRegions(raw.as_ptr());
+    }
+}
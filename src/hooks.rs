@@ -0,0 +1,199 @@
+//! Handlers for fatal kernel events.
+//!
+//! Handlers are plain functions that can be registered at any time, e.g. to log the
+//! event to flash before the chip reboots. They may run inside a critical section or an
+//! ISR, so they must not block or call blocking kernel functions.
+
+use core::fmt;
+use core::mem;
+#[cfg(not(feature = "host-sim"))]
+use core::ptr;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(not(feature = "host-sim"))]
+use crate::backtrace::Backtrace;
+use crate::task::Task;
+use crate::UBaseType_t;
+
+/// RTC control options register.
+/// Taken from `esp-idf/components/soc/esp32/include/soc/rtc_cntl_reg.h`
+#[cfg(feature = "esp32")]
+const RTC_CNTL_OPTIONS0_REG: usize = 0x3FF4_8000;
+/// RTC control options register.
+/// Taken from `esp-idf/components/soc/esp32s2/include/soc/rtc_cntl_reg.h`
+#[cfg(feature = "esp32s2")]
+const RTC_CNTL_OPTIONS0_REG: usize = 0x3F40_8000;
+/// RTC control options register.
+/// Taken from `esp-idf/components/soc/esp32s3/include/soc/rtc_cntl_reg.h`
+#[cfg(feature = "esp32s3")]
+const RTC_CNTL_OPTIONS0_REG: usize = 0x6000_8000;
+/// Software system reset bit of [`RTC_CNTL_OPTIONS0_REG`].
+/// Taken from `esp-idf/components/soc/esp32/include/soc/rtc_cntl_reg.h`
+#[cfg(not(feature = "host-sim"))]
+const RTC_CNTL_SW_SYS_RST: u32 = 1 << 31;
+
+/// Reset the whole chip, like the reset button.
+#[cfg(not(feature = "host-sim"))]
+pub fn restart() -> ! {
+    unsafe {
+        let reg = RTC_CNTL_OPTIONS0_REG as *mut u32;
+        ptr::write_volatile(reg, ptr::read_volatile(reg) | RTC_CNTL_SW_SYS_RST);
+    }
+    loop {
+        core::hint::spin_loop();
+    }
+}
+
+/// The `host-sim` mode has no chip to reset, the process is aborted instead.
+#[cfg(feature = "host-sim")]
+pub fn restart() -> ! {
+    extern "C" {
+        fn abort() -> !;
+    }
+    unsafe { abort() }
+}
+
+/// A failed `configASSERT` in the kernel.
+///
+/// What happens after the assert handler returned is chosen by cargo features:
+/// - default: `panic!` with the formatted [`AssertInfo`].
+/// - `assert-abort`: reset the chip with [`restart`] (without running the panic
+///   handler), or abort the process in the `host-sim` mode.
+/// - `assert-continue`: return to the kernel and continue.
+/// - `assert-disable`: asserts are compiled out, the handler is never called.
+#[derive(Debug, Clone, Copy)]
+pub struct AssertInfo<'a> {
+    /// The C source file of the assert.
+    pub file: &'a str,
+    /// The line of the assert.
+    pub line: usize,
+    /// The C function containing the assert.
+    pub function: &'a str,
+    /// The task that was running, `None` if the scheduler hasn't started yet.
+    pub task: Option<Task>,
+    /// The core the assert failed on.
+    pub core_id: UBaseType_t,
+}
+
+impl fmt::Display for AssertInfo<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{} ({}) - RTOS assert failed on core {}",
+            self.file, self.line, self.function, self.core_id
+        )?;
+        match self.task {
+            // The task that failed the assert is still running.
+            Some(task) => write!(f, " in task '{}'", unsafe { task.name() }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A function that is called when a kernel assert fails.
+pub type AssertHandler = fn(&AssertInfo<'_>);
+
+/// The registered [`AssertHandler`], zero if there is none.
+static ASSERT_HANDLER: AtomicUsize = AtomicUsize::new(0);
+
+/// Register `handler` to be called when a kernel assert fails, replacing the previous
+/// handler.
+pub fn set_assert_handler(handler: AssertHandler) {
+    ASSERT_HANDLER.store(handler as usize, Ordering::Release);
+}
+
+/// Remove the registered assert handler.
+pub fn clear_assert_handler() {
+    ASSERT_HANDLER.store(0, Ordering::Release);
+}
+
+/// Call the registered assert handler, if any.
+pub(crate) fn call_assert_handler(info: &AssertInfo<'_>) {
+    let handler = ASSERT_HANDLER.load(Ordering::Acquire);
+    if handler != 0 {
+        let handler: AssertHandler = unsafe { mem::transmute(handler) };
+        handler(info);
+    }
+}
+
+/// A task that overflowed its stack, detected by the kernel on a context switch.
+///
+/// The handler runs inside the context switch with the kernel locked, so it must not
+/// call into the kernel.
+///
+/// The check uses `configCHECK_FOR_STACK_OVERFLOW` method 2 (a canary at the end of the
+/// stack) by default, or method 1 (the stack pointer on context switches) with the
+/// `stack-overflow-ptrval` feature.
+pub struct StackOverflowReport<'a> {
+    /// The task that overflowed its stack.
+    pub task: Task,
+    /// The name of the task.
+    pub task_name: &'a str,
+    /// The lowest address of the stack of the task.
+    pub stack_start: usize,
+    /// The minimum amount of free stack space in words, usually zero.
+    pub stack_high_water_mark: usize,
+    /// The core the overflow was detected on.
+    pub core_id: UBaseType_t,
+    /// The backtrace of the context switch that detected the overflow, limited to 8
+    /// frames. Clone it to iterate over the frames.
+    ///
+    /// A yielding task switches context on its own stack, i.e. the one that overflowed,
+    /// so the frames past the kernel's may be garbage.
+    #[cfg(not(feature = "host-sim"))]
+    pub backtrace: Backtrace,
+}
+
+impl fmt::Display for StackOverflowReport<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Stack overflow in task '{}' on core {} (stack start: {:#010x}, high water mark: {})",
+            self.task_name, self.core_id, self.stack_start, self.stack_high_water_mark
+        )
+    }
+}
+
+/// What to do after a stack overflow was reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StackOverflowAction {
+    /// `panic!` with the formatted [`StackOverflowReport`], the default.
+    Panic,
+    /// Suspend the task and keep the other tasks running. As the kernel is locked while
+    /// the handler runs, the timer daemon suspends the task afterwards, so the task can
+    /// run again until then unless the daemon has a higher priority (see
+    /// `FREERTOS_TIMER_TASK_PRIORITY`). The memory next to the stack may be corrupted
+    /// already. Panics if the timer command queue is full.
+    Suspend,
+    /// Reset the chip with [`restart`].
+    Restart,
+}
+
+/// A function that is called when a task overflowed its stack.
+pub type StackOverflowHandler = fn(&StackOverflowReport<'_>) -> StackOverflowAction;
+
+/// The registered [`StackOverflowHandler`], zero if there is none.
+static STACK_OVERFLOW_HANDLER: AtomicUsize = AtomicUsize::new(0);
+
+/// Register `handler` to be called when a task overflowed its stack, replacing the
+/// previous handler.
+pub fn set_stack_overflow_handler(handler: StackOverflowHandler) {
+    STACK_OVERFLOW_HANDLER.store(handler as usize, Ordering::Release);
+}
+
+/// Remove the registered stack overflow handler.
+pub fn clear_stack_overflow_handler() {
+    STACK_OVERFLOW_HANDLER.store(0, Ordering::Release);
+}
+
+/// Call the registered stack overflow handler, [`StackOverflowAction::Panic`] if there
+/// is none.
+pub(crate) fn call_stack_overflow_handler(report: &StackOverflowReport<'_>) -> StackOverflowAction {
+    let handler = STACK_OVERFLOW_HANDLER.load(Ordering::Acquire);
+    if handler == 0 {
+        return StackOverflowAction::Panic;
+    }
+
+    let handler: StackOverflowHandler = unsafe { mem::transmute(handler) };
+    handler(report)
+}
@@ -2,33 +2,93 @@
 #![allow(non_upper_case_globals)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
-#![feature(llvm_asm)]
+#![cfg_attr(not(feature = "host-sim"), feature(llvm_asm))]
 
-#[cfg(feature = "use-rust-alloc")]
+// The kernel heaps also register a `#[global_allocator]`.
+#[cfg(any(feature = "use-rust-alloc", feature = "heap-4", feature = "heap-5"))]
 extern crate alloc;
 
+#[cfg(all(
+    feature = "use-rust-alloc",
+    any(feature = "heap-4", feature = "heap-5")
+))]
+compile_error!("`use-rust-alloc` can't be combined with a kernel heap (`heap-4` or `heap-5`)");
+#[cfg(all(feature = "heap-4", feature = "heap-5"))]
+compile_error!("only one of the `heap-4` and `heap-5` features can be enabled");
+#[cfg(any(
+    all(feature = "assert-abort", feature = "assert-continue"),
+    all(feature = "assert-abort", feature = "assert-disable"),
+    all(feature = "assert-continue", feature = "assert-disable")
+))]
+compile_error!("only one of the `assert-*` features can be enabled");
+#[cfg(not(any(
+    feature = "esp32",
+    feature = "esp32s2",
+    feature = "esp32s3",
+    feature = "host-sim"
+)))]
+compile_error!("one of the `esp32`, `esp32s2`, `esp32s3` and `host-sim` features must be enabled");
+#[cfg(any(
+    all(feature = "esp32", feature = "esp32s2"),
+    all(feature = "esp32", feature = "esp32s3"),
+    all(feature = "esp32s2", feature = "esp32s3"),
+    all(feature = "host-sim", any(feature = "esp32", feature = "esp32s2", feature = "esp32s3"))
+))]
+compile_error!("only one of the `esp32`, `esp32s2`, `esp32s3` and `host-sim` features can be enabled");
+// On the host, Rust also allocates from threads the kernel didn't create (e.g. the test
+// harness), which the critical sections of the kernel heaps don't protect against.
+#[cfg(all(feature = "host-sim", any(feature = "heap-4", feature = "heap-5")))]
+compile_error!("the kernel heaps can't be used with `host-sim`, use `use-rust-alloc`");
+
+mod atomic;
+#[cfg(not(feature = "host-sim"))]
 pub mod backtrace;
+#[cfg(not(feature = "bindgen"))]
+#[cfg_attr(feature = "esp32", path = "bindings/esp32.rs")]
+#[cfg_attr(feature = "esp32s2", path = "bindings/esp32s2.rs")]
+#[cfg_attr(feature = "esp32s3", path = "bindings/esp32s3.rs")]
 mod bindings;
+#[cfg(feature = "bindgen")]
+mod bindings {
+    include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+}
+pub mod config;
+#[cfg(not(feature = "host-sim"))]
+pub mod coredump;
+mod daemon;
+pub mod error;
+pub mod event_group;
 pub mod glue;
+#[cfg(any(feature = "use-rust-alloc", feature = "heap-4", feature = "heap-5"))]
+pub mod heap;
+pub mod hooks;
+pub mod macros;
+pub mod mutex;
+pub mod notification;
+pub mod queue;
+pub mod queue_set;
+pub mod semaphore;
+#[cfg(all(test, feature = "host-sim"))]
+mod sim;
+pub mod stream_buffer;
+pub mod task;
+pub mod ticks;
+#[cfg(any(feature = "use-rust-alloc", feature = "heap-4", feature = "heap-5"))]
+pub mod timer;
+pub mod unwind;
 
 pub use bindings::*;
+pub use config::*;
+#[cfg(feature = "use-rust-alloc")]
+pub use glue::pvPortMallocAligned;
 pub use glue::{
-    portGET_CORE_ID, ulTaskEnterCriticalFromISR, vPortYieldFromISR, vTaskExitCriticalFromISR,
+    portDISABLE_INTERRUPTS, portENABLE_INTERRUPTS, portGET_CORE_ID, portNOP,
+    portRESTORE_INTERRUPTS, ulTaskEnterCriticalFromISR, vPortYieldFromISR,
+    vTaskExitCriticalFromISR,
 };
+pub use macros::*;
 
-// TODO: Bindgen should also generate these
-pub const pdTRUE: BaseType_t = 1;
-pub const pdFALSE: BaseType_t = 0;
-pub const pdPASS: BaseType_t = pdTRUE;
-pub const pdFAIL: BaseType_t = pdFALSE;
-
-pub const queueQUEUE_TYPE_BASE: u8 = 0;
-pub const queueQUEUE_TYPE_SET: u8 = 0;
-pub const queueQUEUE_TYPE_MUTEX: u8 = 1;
-pub const queueQUEUE_TYPE_COUNTING_SEMAPHORE: u8 = 2;
-pub const queueQUEUE_TYPE_BINARY_SEMAPHORE: u8 = 3;
-pub const queueQUEUE_TYPE_RECURSIVE_MUTEX: u8 = 4;
-
+#[cfg(not(feature = "host-sim"))]
 pub fn wait_until_core_scheduler_running(core: usize) {
     unsafe {
         let scheduler_running_ptr = &port_scheduler_running[core] as *const _;
@@ -38,3 +98,45 @@ pub fn wait_until_core_scheduler_running(core: usize) {
         }
     }
 }
+
+/// The pre-generated bindings of the chip, to compare their layouts with the generated ones.
+#[cfg(all(feature = "bindgen", freertos_default_config))]
+#[cfg_attr(feature = "esp32", path = "bindings/esp32.rs")]
+#[cfg_attr(feature = "esp32s2", path = "bindings/esp32s2.rs")]
+#[cfg_attr(feature = "esp32s3", path = "bindings/esp32s3.rs")]
+#[allow(dead_code)]
+mod pregenerated;
+
+// The layouts in the pre-generated bindings must match the ones generated by the `bindgen`
+// feature for the default configuration, otherwise `generate_bindings.sh` needs to be run.
+#[cfg(all(feature = "bindgen", freertos_default_config))]
+const _: () = {
+    use core::mem::{align_of, size_of};
+
+    macro_rules! assert_same_layout {
+        ($($ty:ident),*) => {
+            $(
+                assert!(
+                    size_of::<pregenerated::$ty>() == size_of::<bindings::$ty>()
+                        && align_of::<pregenerated::$ty>() == align_of::<bindings::$ty>(),
+                    concat!(
+                        "the pre-generated layout of `",
+                        stringify!($ty),
+                        "` is outdated, run `generate_bindings.sh`"
+                    )
+                );
+            )*
+        };
+    }
+
+    assert_same_layout!(
+        StaticTask_t,
+        StaticQueue_t,
+        StaticEventGroup_t,
+        StaticStreamBuffer_t,
+        StaticTimer_t,
+        TaskStatus_t,
+        XtExcFrame,
+        XtSolFrame
+    );
+};
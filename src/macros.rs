@@ -0,0 +1,1272 @@
+//! The parts of the FreeRTOS API that are C macros, and therefore missing from the
+//! bindings, as functions and constants with the same names, arguments and semantics.
+//!
+//! Like the bindings they work on raw handles, e.g. `xSemaphoreTake(handle, portMAX_DELAY)`
+//! can be called as in C. Macros that are only defined with `configSUPPORT_STATIC_ALLOCATION`
+//! need the `static-alloc` feature. The interrupt macros of the port (`portGET_CORE_ID`,
+//! `portDISABLE_INTERRUPTS`, ...) are implemented in the `glue` module, in assembly for the
+//! xtensa port.
+
+// The safety requirements are the ones of the C API.
+#![allow(clippy::missing_safety_doc)]
+
+use core::ptr;
+
+use chlorine::c_void;
+
+use crate::{
+    configNUM_CORES, configTICK_RATE_HZ, eNotifyAction, eNotifyAction_eIncrement,
+    tskDEFAULT_INDEX_TO_NOTIFY, ulTaskGenericNotifyTake, ulTaskGenericNotifyValueClear,
+    uxQueueMessagesWaiting, vEventGroupClearBitsCallback, vEventGroupSetBitsCallback, vPortYield,
+    vQueueDelete, vStreamBufferDelete, vTaskGenericNotifyGiveFromISR, xEventGroupClearBits,
+    xQueueCreateCountingSemaphore, xQueueCreateMutex, xQueueGenericCreate, xQueueGenericReset,
+    xQueueGenericSend, xQueueGenericSendFromISR, xQueueGetMutexHolder, xQueueGetMutexHolderFromISR,
+    xQueueGiveFromISR, xQueueGiveMutexRecursive, xQueueReceiveFromISR, xQueueSemaphoreTake,
+    xQueueTakeMutexRecursive, xStreamBufferGenericCreate, xStreamBufferIsEmpty,
+    xStreamBufferIsFull, xStreamBufferNextMessageLengthBytes, xStreamBufferReceive,
+    xStreamBufferReceiveCompletedFromISR, xStreamBufferReceiveFromISR, xStreamBufferReset,
+    xStreamBufferSend, xStreamBufferSendCompletedFromISR, xStreamBufferSendFromISR,
+    xStreamBufferSpacesAvailable, xTaskDelayUntil, xTaskGenericNotify, xTaskGenericNotifyFromISR,
+    xTaskGenericNotifyStateClear, xTaskGenericNotifyWait, xTaskGetTickCount,
+    xTaskGetTickCountFromISR, xTimerGenericCommandFromISR, xTimerGenericCommandFromTask,
+    xTimerPendFunctionCallFromISR, BaseType_t, EventBits_t, EventGroupHandle_t,
+    MessageBufferHandle_t, QueueHandle_t, SemaphoreHandle_t, StreamBufferHandle_t, TaskHandle_t,
+    TickType_t, TimerHandle_t, UBaseType_t,
+};
+
+#[cfg(feature = "host-sim")]
+pub use host_impl::*;
+#[cfg(feature = "static-alloc")]
+pub use static_impl::*;
+#[cfg(not(feature = "host-sim"))]
+pub use xtensa_impl::*;
+
+// projdefs.h
+
+pub const pdTRUE: BaseType_t = 1;
+pub const pdFALSE: BaseType_t = 0;
+pub const pdPASS: BaseType_t = pdTRUE;
+pub const pdFAIL: BaseType_t = pdFALSE;
+pub const errQUEUE_EMPTY: BaseType_t = 0;
+pub const errQUEUE_FULL: BaseType_t = 0;
+
+/// Convert milliseconds to ticks, rounding down.
+///
+/// Like the C macro the multiplication wraps around for large values, see
+/// [`Ticks::from_ms`](crate::ticks::Ticks::from_ms) for a conversion that doesn't.
+#[inline]
+pub const fn pdMS_TO_TICKS(xTimeInMs: TickType_t) -> TickType_t {
+    xTimeInMs.wrapping_mul(configTICK_RATE_HZ) / 1000
+}
+
+// portmacro.h
+
+pub const portTICK_PERIOD_MS: TickType_t = 1000 / configTICK_RATE_HZ;
+
+#[inline]
+pub unsafe fn portYIELD() {
+    vPortYield()
+}
+
+/// Request a context switch when the ISR returns if `xSwitchRequired` is set.
+#[inline]
+pub unsafe fn portYIELD_FROM_ISR(xSwitchRequired: BaseType_t) {
+    if xSwitchRequired != pdFALSE {
+        crate::vPortYieldFromISR();
+    }
+}
+
+// task.h
+
+pub const tskIDLE_PRIORITY: UBaseType_t = 0;
+pub const tskNO_AFFINITY: UBaseType_t = UBaseType_t::MAX;
+
+pub const taskSCHEDULER_SUSPENDED: BaseType_t = 0;
+pub const taskSCHEDULER_NOT_STARTED: BaseType_t = 1;
+pub const taskSCHEDULER_RUNNING: BaseType_t = 2;
+
+/// `tskDEFAULT_INDEX_TO_NOTIFY` is a `u32` in the bindings, the index arguments are
+/// `UBaseType_t`, which is wider on the POSIX port of `host-sim`.
+const DEFAULT_INDEX_TO_NOTIFY: UBaseType_t = tskDEFAULT_INDEX_TO_NOTIFY as UBaseType_t;
+
+#[inline]
+pub const fn taskVALID_CORE_ID(xCoreID: BaseType_t) -> BaseType_t {
+    (0 <= xCoreID && xCoreID < configNUM_CORES as BaseType_t) as BaseType_t
+}
+
+#[inline]
+pub unsafe fn taskYIELD() {
+    portYIELD()
+}
+
+#[inline]
+pub unsafe fn taskENTER_CRITICAL() {
+    portENTER_CRITICAL()
+}
+
+#[inline]
+pub unsafe fn taskEXIT_CRITICAL() {
+    portEXIT_CRITICAL()
+}
+
+#[inline]
+pub unsafe fn taskENTER_CRITICAL_FROM_ISR() -> UBaseType_t {
+    portSET_INTERRUPT_MASK_FROM_ISR()
+}
+
+#[inline]
+pub unsafe fn taskEXIT_CRITICAL_FROM_ISR(x: UBaseType_t) {
+    portCLEAR_INTERRUPT_MASK_FROM_ISR(x)
+}
+
+#[inline]
+pub unsafe fn vTaskDelayUntil(pxPreviousWakeTime: *mut TickType_t, xTimeIncrement: TickType_t) {
+    xTaskDelayUntil(pxPreviousWakeTime, xTimeIncrement);
+}
+
+#[inline]
+pub unsafe fn xTaskNotify(
+    xTaskToNotify: TaskHandle_t,
+    ulValue: u32,
+    eAction: eNotifyAction,
+) -> BaseType_t {
+    xTaskNotifyIndexed(xTaskToNotify, DEFAULT_INDEX_TO_NOTIFY, ulValue, eAction)
+}
+
+#[inline]
+pub unsafe fn xTaskNotifyIndexed(
+    xTaskToNotify: TaskHandle_t,
+    uxIndexToNotify: UBaseType_t,
+    ulValue: u32,
+    eAction: eNotifyAction,
+) -> BaseType_t {
+    xTaskGenericNotify(
+        xTaskToNotify,
+        uxIndexToNotify,
+        ulValue,
+        eAction,
+        ptr::null_mut(),
+    )
+}
+
+#[inline]
+pub unsafe fn xTaskNotifyAndQuery(
+    xTaskToNotify: TaskHandle_t,
+    ulValue: u32,
+    eAction: eNotifyAction,
+    pulPreviousNotifyValue: *mut u32,
+) -> BaseType_t {
+    xTaskNotifyAndQueryIndexed(
+        xTaskToNotify,
+        DEFAULT_INDEX_TO_NOTIFY,
+        ulValue,
+        eAction,
+        pulPreviousNotifyValue,
+    )
+}
+
+#[inline]
+pub unsafe fn xTaskNotifyAndQueryIndexed(
+    xTaskToNotify: TaskHandle_t,
+    uxIndexToNotify: UBaseType_t,
+    ulValue: u32,
+    eAction: eNotifyAction,
+    pulPreviousNotifyValue: *mut u32,
+) -> BaseType_t {
+    xTaskGenericNotify(
+        xTaskToNotify,
+        uxIndexToNotify,
+        ulValue,
+        eAction,
+        pulPreviousNotifyValue,
+    )
+}
+
+#[inline]
+pub unsafe fn xTaskNotifyFromISR(
+    xTaskToNotify: TaskHandle_t,
+    ulValue: u32,
+    eAction: eNotifyAction,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xTaskNotifyIndexedFromISR(
+        xTaskToNotify,
+        DEFAULT_INDEX_TO_NOTIFY,
+        ulValue,
+        eAction,
+        pxHigherPriorityTaskWoken,
+    )
+}
+
+#[inline]
+pub unsafe fn xTaskNotifyIndexedFromISR(
+    xTaskToNotify: TaskHandle_t,
+    uxIndexToNotify: UBaseType_t,
+    ulValue: u32,
+    eAction: eNotifyAction,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xTaskGenericNotifyFromISR(
+        xTaskToNotify,
+        uxIndexToNotify,
+        ulValue,
+        eAction,
+        ptr::null_mut(),
+        pxHigherPriorityTaskWoken,
+    )
+}
+
+#[inline]
+pub unsafe fn xTaskNotifyAndQueryFromISR(
+    xTaskToNotify: TaskHandle_t,
+    ulValue: u32,
+    eAction: eNotifyAction,
+    pulPreviousNotificationValue: *mut u32,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xTaskNotifyAndQueryIndexedFromISR(
+        xTaskToNotify,
+        DEFAULT_INDEX_TO_NOTIFY,
+        ulValue,
+        eAction,
+        pulPreviousNotificationValue,
+        pxHigherPriorityTaskWoken,
+    )
+}
+
+#[inline]
+pub unsafe fn xTaskNotifyAndQueryIndexedFromISR(
+    xTaskToNotify: TaskHandle_t,
+    uxIndexToNotify: UBaseType_t,
+    ulValue: u32,
+    eAction: eNotifyAction,
+    pulPreviousNotificationValue: *mut u32,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xTaskGenericNotifyFromISR(
+        xTaskToNotify,
+        uxIndexToNotify,
+        ulValue,
+        eAction,
+        pulPreviousNotificationValue,
+        pxHigherPriorityTaskWoken,
+    )
+}
+
+#[inline]
+pub unsafe fn xTaskNotifyWait(
+    ulBitsToClearOnEntry: u32,
+    ulBitsToClearOnExit: u32,
+    pulNotificationValue: *mut u32,
+    xTicksToWait: TickType_t,
+) -> BaseType_t {
+    xTaskGenericNotifyWait(
+        DEFAULT_INDEX_TO_NOTIFY,
+        ulBitsToClearOnEntry,
+        ulBitsToClearOnExit,
+        pulNotificationValue,
+        xTicksToWait,
+    )
+}
+
+#[inline]
+pub unsafe fn xTaskNotifyWaitIndexed(
+    uxIndexToWaitOn: UBaseType_t,
+    ulBitsToClearOnEntry: u32,
+    ulBitsToClearOnExit: u32,
+    pulNotificationValue: *mut u32,
+    xTicksToWait: TickType_t,
+) -> BaseType_t {
+    xTaskGenericNotifyWait(
+        uxIndexToWaitOn,
+        ulBitsToClearOnEntry,
+        ulBitsToClearOnExit,
+        pulNotificationValue,
+        xTicksToWait,
+    )
+}
+
+#[inline]
+pub unsafe fn xTaskNotifyGive(xTaskToNotify: TaskHandle_t) -> BaseType_t {
+    xTaskNotifyGiveIndexed(xTaskToNotify, DEFAULT_INDEX_TO_NOTIFY)
+}
+
+#[inline]
+pub unsafe fn xTaskNotifyGiveIndexed(
+    xTaskToNotify: TaskHandle_t,
+    uxIndexToNotify: UBaseType_t,
+) -> BaseType_t {
+    xTaskGenericNotify(
+        xTaskToNotify,
+        uxIndexToNotify,
+        0,
+        eNotifyAction_eIncrement,
+        ptr::null_mut(),
+    )
+}
+
+#[inline]
+pub unsafe fn vTaskNotifyGiveFromISR(
+    xTaskToNotify: TaskHandle_t,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) {
+    vTaskGenericNotifyGiveFromISR(
+        xTaskToNotify,
+        DEFAULT_INDEX_TO_NOTIFY,
+        pxHigherPriorityTaskWoken,
+    )
+}
+
+#[inline]
+pub unsafe fn vTaskNotifyGiveIndexedFromISR(
+    xTaskToNotify: TaskHandle_t,
+    uxIndexToNotify: UBaseType_t,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) {
+    vTaskGenericNotifyGiveFromISR(xTaskToNotify, uxIndexToNotify, pxHigherPriorityTaskWoken)
+}
+
+#[inline]
+pub unsafe fn ulTaskNotifyTake(xClearCountOnExit: BaseType_t, xTicksToWait: TickType_t) -> u32 {
+    ulTaskGenericNotifyTake(DEFAULT_INDEX_TO_NOTIFY, xClearCountOnExit, xTicksToWait)
+}
+
+#[inline]
+pub unsafe fn ulTaskNotifyTakeIndexed(
+    uxIndexToWaitOn: UBaseType_t,
+    xClearCountOnExit: BaseType_t,
+    xTicksToWait: TickType_t,
+) -> u32 {
+    ulTaskGenericNotifyTake(uxIndexToWaitOn, xClearCountOnExit, xTicksToWait)
+}
+
+#[inline]
+pub unsafe fn xTaskNotifyStateClear(xTask: TaskHandle_t) -> BaseType_t {
+    xTaskGenericNotifyStateClear(xTask, DEFAULT_INDEX_TO_NOTIFY)
+}
+
+#[inline]
+pub unsafe fn xTaskNotifyStateClearIndexed(
+    xTask: TaskHandle_t,
+    uxIndexToClear: UBaseType_t,
+) -> BaseType_t {
+    xTaskGenericNotifyStateClear(xTask, uxIndexToClear)
+}
+
+#[inline]
+pub unsafe fn ulTaskNotifyValueClear(xTask: TaskHandle_t, ulBitsToClear: u32) -> u32 {
+    ulTaskGenericNotifyValueClear(xTask, DEFAULT_INDEX_TO_NOTIFY, ulBitsToClear)
+}
+
+#[inline]
+pub unsafe fn ulTaskNotifyValueClearIndexed(
+    xTask: TaskHandle_t,
+    uxIndexToClear: UBaseType_t,
+    ulBitsToClear: u32,
+) -> u32 {
+    ulTaskGenericNotifyValueClear(xTask, uxIndexToClear, ulBitsToClear)
+}
+
+// queue.h
+
+pub const queueQUEUE_TYPE_BASE: u8 = 0;
+// Same as `queueQUEUE_TYPE_BASE` in `queue.h`, queue sets are plain queues of handles.
+pub const queueQUEUE_TYPE_SET: u8 = 0;
+pub const queueQUEUE_TYPE_MUTEX: u8 = 1;
+pub const queueQUEUE_TYPE_COUNTING_SEMAPHORE: u8 = 2;
+pub const queueQUEUE_TYPE_BINARY_SEMAPHORE: u8 = 3;
+pub const queueQUEUE_TYPE_RECURSIVE_MUTEX: u8 = 4;
+
+pub const queueSEND_TO_BACK: BaseType_t = 0;
+pub const queueSEND_TO_FRONT: BaseType_t = 1;
+pub const queueOVERWRITE: BaseType_t = 2;
+
+#[inline]
+pub unsafe fn xQueueCreate(uxQueueLength: UBaseType_t, uxItemSize: UBaseType_t) -> QueueHandle_t {
+    xQueueGenericCreate(uxQueueLength, uxItemSize, queueQUEUE_TYPE_BASE)
+}
+
+#[inline]
+pub unsafe fn xQueueSend(
+    xQueue: QueueHandle_t,
+    pvItemToQueue: *const c_void,
+    xTicksToWait: TickType_t,
+) -> BaseType_t {
+    xQueueGenericSend(xQueue, pvItemToQueue, xTicksToWait, queueSEND_TO_BACK)
+}
+
+#[inline]
+pub unsafe fn xQueueSendToBack(
+    xQueue: QueueHandle_t,
+    pvItemToQueue: *const c_void,
+    xTicksToWait: TickType_t,
+) -> BaseType_t {
+    xQueueGenericSend(xQueue, pvItemToQueue, xTicksToWait, queueSEND_TO_BACK)
+}
+
+#[inline]
+pub unsafe fn xQueueSendToFront(
+    xQueue: QueueHandle_t,
+    pvItemToQueue: *const c_void,
+    xTicksToWait: TickType_t,
+) -> BaseType_t {
+    xQueueGenericSend(xQueue, pvItemToQueue, xTicksToWait, queueSEND_TO_FRONT)
+}
+
+#[inline]
+pub unsafe fn xQueueOverwrite(xQueue: QueueHandle_t, pvItemToQueue: *const c_void) -> BaseType_t {
+    xQueueGenericSend(xQueue, pvItemToQueue, 0, queueOVERWRITE)
+}
+
+#[inline]
+pub unsafe fn xQueueSendFromISR(
+    xQueue: QueueHandle_t,
+    pvItemToQueue: *const c_void,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xQueueGenericSendFromISR(
+        xQueue,
+        pvItemToQueue,
+        pxHigherPriorityTaskWoken,
+        queueSEND_TO_BACK,
+    )
+}
+
+#[inline]
+pub unsafe fn xQueueSendToBackFromISR(
+    xQueue: QueueHandle_t,
+    pvItemToQueue: *const c_void,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xQueueGenericSendFromISR(
+        xQueue,
+        pvItemToQueue,
+        pxHigherPriorityTaskWoken,
+        queueSEND_TO_BACK,
+    )
+}
+
+#[inline]
+pub unsafe fn xQueueSendToFrontFromISR(
+    xQueue: QueueHandle_t,
+    pvItemToQueue: *const c_void,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xQueueGenericSendFromISR(
+        xQueue,
+        pvItemToQueue,
+        pxHigherPriorityTaskWoken,
+        queueSEND_TO_FRONT,
+    )
+}
+
+#[inline]
+pub unsafe fn xQueueOverwriteFromISR(
+    xQueue: QueueHandle_t,
+    pvItemToQueue: *const c_void,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xQueueGenericSendFromISR(
+        xQueue,
+        pvItemToQueue,
+        pxHigherPriorityTaskWoken,
+        queueOVERWRITE,
+    )
+}
+
+#[inline]
+pub unsafe fn xQueueReset(xQueue: QueueHandle_t) -> BaseType_t {
+    xQueueGenericReset(xQueue, pdFALSE)
+}
+
+// semphr.h
+
+pub const semBINARY_SEMAPHORE_QUEUE_LENGTH: UBaseType_t = 1;
+pub const semSEMAPHORE_QUEUE_ITEM_LENGTH: UBaseType_t = 0;
+pub const semGIVE_BLOCK_TIME: TickType_t = 0;
+
+/// Create a binary semaphore that is initially given, the old form of
+/// [`xSemaphoreCreateBinary`].
+#[inline]
+pub unsafe fn vSemaphoreCreateBinary(xSemaphore: &mut SemaphoreHandle_t) {
+    *xSemaphore = xQueueGenericCreate(
+        1,
+        semSEMAPHORE_QUEUE_ITEM_LENGTH,
+        queueQUEUE_TYPE_BINARY_SEMAPHORE,
+    );
+    if !xSemaphore.is_null() {
+        xSemaphoreGive(*xSemaphore);
+    }
+}
+
+#[inline]
+pub unsafe fn xSemaphoreCreateBinary() -> SemaphoreHandle_t {
+    xQueueGenericCreate(
+        1,
+        semSEMAPHORE_QUEUE_ITEM_LENGTH,
+        queueQUEUE_TYPE_BINARY_SEMAPHORE,
+    )
+}
+
+#[inline]
+pub unsafe fn xSemaphoreTake(xSemaphore: SemaphoreHandle_t, xBlockTime: TickType_t) -> BaseType_t {
+    xQueueSemaphoreTake(xSemaphore, xBlockTime)
+}
+
+#[inline]
+pub unsafe fn xSemaphoreTakeRecursive(
+    xMutex: SemaphoreHandle_t,
+    xBlockTime: TickType_t,
+) -> BaseType_t {
+    xQueueTakeMutexRecursive(xMutex, xBlockTime)
+}
+
+#[inline]
+pub unsafe fn xSemaphoreGive(xSemaphore: SemaphoreHandle_t) -> BaseType_t {
+    xQueueGenericSend(
+        xSemaphore,
+        ptr::null(),
+        semGIVE_BLOCK_TIME,
+        queueSEND_TO_BACK,
+    )
+}
+
+#[inline]
+pub unsafe fn xSemaphoreGiveRecursive(xMutex: SemaphoreHandle_t) -> BaseType_t {
+    xQueueGiveMutexRecursive(xMutex)
+}
+
+#[inline]
+pub unsafe fn xSemaphoreGiveFromISR(
+    xSemaphore: SemaphoreHandle_t,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xQueueGiveFromISR(xSemaphore, pxHigherPriorityTaskWoken)
+}
+
+#[inline]
+pub unsafe fn xSemaphoreTakeFromISR(
+    xSemaphore: SemaphoreHandle_t,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xQueueReceiveFromISR(xSemaphore, ptr::null_mut(), pxHigherPriorityTaskWoken)
+}
+
+#[inline]
+pub unsafe fn xSemaphoreCreateMutex() -> SemaphoreHandle_t {
+    xQueueCreateMutex(queueQUEUE_TYPE_MUTEX)
+}
+
+#[inline]
+pub unsafe fn xSemaphoreCreateRecursiveMutex() -> SemaphoreHandle_t {
+    xQueueCreateMutex(queueQUEUE_TYPE_RECURSIVE_MUTEX)
+}
+
+#[inline]
+pub unsafe fn xSemaphoreCreateCounting(
+    uxMaxCount: UBaseType_t,
+    uxInitialCount: UBaseType_t,
+) -> SemaphoreHandle_t {
+    xQueueCreateCountingSemaphore(uxMaxCount, uxInitialCount)
+}
+
+#[inline]
+pub unsafe fn vSemaphoreDelete(xSemaphore: SemaphoreHandle_t) {
+    vQueueDelete(xSemaphore)
+}
+
+#[inline]
+pub unsafe fn xSemaphoreGetMutexHolder(xSemaphore: SemaphoreHandle_t) -> TaskHandle_t {
+    xQueueGetMutexHolder(xSemaphore)
+}
+
+#[inline]
+pub unsafe fn xSemaphoreGetMutexHolderFromISR(xSemaphore: SemaphoreHandle_t) -> TaskHandle_t {
+    xQueueGetMutexHolderFromISR(xSemaphore)
+}
+
+#[inline]
+pub unsafe fn uxSemaphoreGetCount(xSemaphore: SemaphoreHandle_t) -> UBaseType_t {
+    uxQueueMessagesWaiting(xSemaphore)
+}
+
+// timers.h
+
+pub const tmrCOMMAND_EXECUTE_CALLBACK_FROM_ISR: BaseType_t = -2;
+pub const tmrCOMMAND_EXECUTE_CALLBACK: BaseType_t = -1;
+pub const tmrCOMMAND_START_DONT_TRACE: BaseType_t = 0;
+pub const tmrCOMMAND_START: BaseType_t = 1;
+pub const tmrCOMMAND_RESET: BaseType_t = 2;
+pub const tmrCOMMAND_STOP: BaseType_t = 3;
+pub const tmrCOMMAND_CHANGE_PERIOD: BaseType_t = 4;
+pub const tmrCOMMAND_DELETE: BaseType_t = 5;
+pub const tmrFIRST_FROM_ISR_COMMAND: BaseType_t = 6;
+pub const tmrCOMMAND_START_FROM_ISR: BaseType_t = 6;
+pub const tmrCOMMAND_RESET_FROM_ISR: BaseType_t = 7;
+pub const tmrCOMMAND_STOP_FROM_ISR: BaseType_t = 8;
+pub const tmrCOMMAND_CHANGE_PERIOD_FROM_ISR: BaseType_t = 9;
+
+/// Send `xCommandID` to the timer daemon, from an ISR for the `*_FROM_ISR` commands.
+#[inline]
+pub unsafe fn xTimerGenericCommand(
+    xTimer: TimerHandle_t,
+    xCommandID: BaseType_t,
+    xOptionalValue: TickType_t,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+    xTicksToWait: TickType_t,
+) -> BaseType_t {
+    if xCommandID < tmrFIRST_FROM_ISR_COMMAND {
+        xTimerGenericCommandFromTask(
+            xTimer,
+            xCommandID,
+            xOptionalValue,
+            pxHigherPriorityTaskWoken,
+            xTicksToWait,
+        )
+    } else {
+        xTimerGenericCommandFromISR(
+            xTimer,
+            xCommandID,
+            xOptionalValue,
+            pxHigherPriorityTaskWoken,
+            xTicksToWait,
+        )
+    }
+}
+
+#[inline]
+pub unsafe fn xTimerStart(xTimer: TimerHandle_t, xTicksToWait: TickType_t) -> BaseType_t {
+    xTimerGenericCommand(
+        xTimer,
+        tmrCOMMAND_START,
+        xTaskGetTickCount(),
+        ptr::null_mut(),
+        xTicksToWait,
+    )
+}
+
+#[inline]
+pub unsafe fn xTimerStop(xTimer: TimerHandle_t, xTicksToWait: TickType_t) -> BaseType_t {
+    xTimerGenericCommand(xTimer, tmrCOMMAND_STOP, 0, ptr::null_mut(), xTicksToWait)
+}
+
+#[inline]
+pub unsafe fn xTimerChangePeriod(
+    xTimer: TimerHandle_t,
+    xNewPeriod: TickType_t,
+    xTicksToWait: TickType_t,
+) -> BaseType_t {
+    xTimerGenericCommand(
+        xTimer,
+        tmrCOMMAND_CHANGE_PERIOD,
+        xNewPeriod,
+        ptr::null_mut(),
+        xTicksToWait,
+    )
+}
+
+#[inline]
+pub unsafe fn xTimerDelete(xTimer: TimerHandle_t, xTicksToWait: TickType_t) -> BaseType_t {
+    xTimerGenericCommand(xTimer, tmrCOMMAND_DELETE, 0, ptr::null_mut(), xTicksToWait)
+}
+
+#[inline]
+pub unsafe fn xTimerReset(xTimer: TimerHandle_t, xTicksToWait: TickType_t) -> BaseType_t {
+    xTimerGenericCommand(
+        xTimer,
+        tmrCOMMAND_RESET,
+        xTaskGetTickCount(),
+        ptr::null_mut(),
+        xTicksToWait,
+    )
+}
+
+#[inline]
+pub unsafe fn xTimerStartFromISR(
+    xTimer: TimerHandle_t,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xTimerGenericCommand(
+        xTimer,
+        tmrCOMMAND_START_FROM_ISR,
+        xTaskGetTickCountFromISR(),
+        pxHigherPriorityTaskWoken,
+        0,
+    )
+}
+
+#[inline]
+pub unsafe fn xTimerStopFromISR(
+    xTimer: TimerHandle_t,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xTimerGenericCommand(
+        xTimer,
+        tmrCOMMAND_STOP_FROM_ISR,
+        0,
+        pxHigherPriorityTaskWoken,
+        0,
+    )
+}
+
+#[inline]
+pub unsafe fn xTimerChangePeriodFromISR(
+    xTimer: TimerHandle_t,
+    xNewPeriod: TickType_t,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xTimerGenericCommand(
+        xTimer,
+        tmrCOMMAND_CHANGE_PERIOD_FROM_ISR,
+        xNewPeriod,
+        pxHigherPriorityTaskWoken,
+        0,
+    )
+}
+
+#[inline]
+pub unsafe fn xTimerResetFromISR(
+    xTimer: TimerHandle_t,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xTimerGenericCommand(
+        xTimer,
+        tmrCOMMAND_RESET_FROM_ISR,
+        xTaskGetTickCountFromISR(),
+        pxHigherPriorityTaskWoken,
+        0,
+    )
+}
+
+// event_groups.h
+
+#[inline]
+pub unsafe fn xEventGroupGetBits(xEventGroup: EventGroupHandle_t) -> EventBits_t {
+    xEventGroupClearBits(xEventGroup, 0)
+}
+
+/// Defer setting `uxBitsToSet` to the timer daemon task.
+#[inline]
+pub unsafe fn xEventGroupSetBitsFromISR(
+    xEventGroup: EventGroupHandle_t,
+    uxBitsToSet: EventBits_t,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xTimerPendFunctionCallFromISR(
+        Some(vEventGroupSetBitsCallback),
+        xEventGroup as *mut c_void,
+        uxBitsToSet,
+        pxHigherPriorityTaskWoken,
+    )
+}
+
+/// Defer clearing `uxBitsToClear` to the timer daemon task.
+#[inline]
+pub unsafe fn xEventGroupClearBitsFromISR(
+    xEventGroup: EventGroupHandle_t,
+    uxBitsToClear: EventBits_t,
+) -> BaseType_t {
+    xTimerPendFunctionCallFromISR(
+        Some(vEventGroupClearBitsCallback),
+        xEventGroup as *mut c_void,
+        uxBitsToClear,
+        ptr::null_mut(),
+    )
+}
+
+// stream_buffer.h
+
+#[inline]
+pub unsafe fn xStreamBufferCreate(
+    xBufferSizeBytes: usize,
+    xTriggerLevelBytes: usize,
+) -> StreamBufferHandle_t {
+    xStreamBufferGenericCreate(xBufferSizeBytes, xTriggerLevelBytes, pdFALSE)
+}
+
+// message_buffer.h
+
+#[inline]
+pub unsafe fn xMessageBufferCreate(xBufferSizeBytes: usize) -> MessageBufferHandle_t {
+    xStreamBufferGenericCreate(xBufferSizeBytes, 0, pdTRUE) as MessageBufferHandle_t
+}
+
+#[inline]
+pub unsafe fn xMessageBufferSend(
+    xMessageBuffer: MessageBufferHandle_t,
+    pvTxData: *const c_void,
+    xDataLengthBytes: usize,
+    xTicksToWait: TickType_t,
+) -> usize {
+    xStreamBufferSend(
+        xMessageBuffer as StreamBufferHandle_t,
+        pvTxData,
+        xDataLengthBytes,
+        xTicksToWait,
+    )
+}
+
+#[inline]
+pub unsafe fn xMessageBufferSendFromISR(
+    xMessageBuffer: MessageBufferHandle_t,
+    pvTxData: *const c_void,
+    xDataLengthBytes: usize,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> usize {
+    xStreamBufferSendFromISR(
+        xMessageBuffer as StreamBufferHandle_t,
+        pvTxData,
+        xDataLengthBytes,
+        pxHigherPriorityTaskWoken,
+    )
+}
+
+#[inline]
+pub unsafe fn xMessageBufferReceive(
+    xMessageBuffer: MessageBufferHandle_t,
+    pvRxData: *mut c_void,
+    xBufferLengthBytes: usize,
+    xTicksToWait: TickType_t,
+) -> usize {
+    xStreamBufferReceive(
+        xMessageBuffer as StreamBufferHandle_t,
+        pvRxData,
+        xBufferLengthBytes,
+        xTicksToWait,
+    )
+}
+
+#[inline]
+pub unsafe fn xMessageBufferReceiveFromISR(
+    xMessageBuffer: MessageBufferHandle_t,
+    pvRxData: *mut c_void,
+    xBufferLengthBytes: usize,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> usize {
+    xStreamBufferReceiveFromISR(
+        xMessageBuffer as StreamBufferHandle_t,
+        pvRxData,
+        xBufferLengthBytes,
+        pxHigherPriorityTaskWoken,
+    )
+}
+
+#[inline]
+pub unsafe fn vMessageBufferDelete(xMessageBuffer: MessageBufferHandle_t) {
+    vStreamBufferDelete(xMessageBuffer as StreamBufferHandle_t)
+}
+
+#[inline]
+pub unsafe fn xMessageBufferIsFull(xMessageBuffer: MessageBufferHandle_t) -> BaseType_t {
+    xStreamBufferIsFull(xMessageBuffer as StreamBufferHandle_t)
+}
+
+#[inline]
+pub unsafe fn xMessageBufferIsEmpty(xMessageBuffer: MessageBufferHandle_t) -> BaseType_t {
+    xStreamBufferIsEmpty(xMessageBuffer as StreamBufferHandle_t)
+}
+
+#[inline]
+pub unsafe fn xMessageBufferReset(xMessageBuffer: MessageBufferHandle_t) -> BaseType_t {
+    xStreamBufferReset(xMessageBuffer as StreamBufferHandle_t)
+}
+
+#[inline]
+pub unsafe fn xMessageBufferSpaceAvailable(xMessageBuffer: MessageBufferHandle_t) -> usize {
+    xStreamBufferSpacesAvailable(xMessageBuffer as StreamBufferHandle_t)
+}
+
+#[inline]
+pub unsafe fn xMessageBufferSpacesAvailable(xMessageBuffer: MessageBufferHandle_t) -> usize {
+    xStreamBufferSpacesAvailable(xMessageBuffer as StreamBufferHandle_t)
+}
+
+#[inline]
+pub unsafe fn xMessageBufferNextLengthBytes(xMessageBuffer: MessageBufferHandle_t) -> usize {
+    xStreamBufferNextMessageLengthBytes(xMessageBuffer as StreamBufferHandle_t)
+}
+
+#[inline]
+pub unsafe fn xMessageBufferSendCompletedFromISR(
+    xMessageBuffer: MessageBufferHandle_t,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xStreamBufferSendCompletedFromISR(
+        xMessageBuffer as StreamBufferHandle_t,
+        pxHigherPriorityTaskWoken,
+    )
+}
+
+#[inline]
+pub unsafe fn xMessageBufferReceiveCompletedFromISR(
+    xMessageBuffer: MessageBufferHandle_t,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xStreamBufferReceiveCompletedFromISR(
+        xMessageBuffer as StreamBufferHandle_t,
+        pxHigherPriorityTaskWoken,
+    )
+}
+
+// The port macros that differ between the xtensa port and the POSIX port of `host-sim`.
+#[cfg(not(feature = "host-sim"))]
+mod xtensa_impl {
+    use crate::{
+        portDISABLE_INTERRUPTS, portENABLE_INTERRUPTS, portGET_CORE_ID, portRESTORE_INTERRUPTS,
+        port_interrupt_nesting, vPortYieldCore, vTaskEnterCritical, vTaskExitCritical, BaseType_t,
+        UBaseType_t,
+    };
+
+    #[inline]
+    pub unsafe fn portYIELD_CORE(xCoreID: BaseType_t) {
+        vPortYieldCore(xCoreID)
+    }
+
+    #[inline]
+    pub unsafe fn taskDISABLE_INTERRUPTS() {
+        portDISABLE_INTERRUPTS();
+    }
+
+    #[inline]
+    pub unsafe fn taskENABLE_INTERRUPTS() {
+        portENABLE_INTERRUPTS();
+    }
+
+    #[inline]
+    pub unsafe fn portENTER_CRITICAL() {
+        vTaskEnterCritical()
+    }
+
+    #[inline]
+    pub unsafe fn portEXIT_CRITICAL() {
+        vTaskExitCritical()
+    }
+
+    /// Disable interrupts and enter a critical section, returns the previous interrupt state
+    /// for [`portCLEAR_INTERRUPT_MASK_FROM_ISR`].
+    #[inline]
+    pub unsafe fn portSET_INTERRUPT_MASK_FROM_ISR() -> UBaseType_t {
+        let mask = portDISABLE_INTERRUPTS();
+        vTaskEnterCritical();
+        mask
+    }
+
+    #[inline]
+    pub unsafe fn portCLEAR_INTERRUPT_MASK_FROM_ISR(x: UBaseType_t) {
+        vTaskExitCritical();
+        portRESTORE_INTERRUPTS(x)
+    }
+
+    /// Returns non-zero when called from an ISR.
+    #[inline]
+    pub fn portCHECK_IF_IN_ISR() -> BaseType_t {
+        unsafe {
+            let status = portDISABLE_INTERRUPTS();
+            let in_interrupt = port_interrupt_nesting[portGET_CORE_ID() as usize] != 0;
+            portRESTORE_INTERRUPTS(status);
+            in_interrupt as BaseType_t
+        }
+    }
+}
+
+#[cfg(feature = "host-sim")]
+mod host_impl {
+    use crate::{
+        pdFALSE, portYIELD, vPortClearInterruptMask, vPortDisableInterrupts, vPortEnableInterrupts,
+        vPortEnterCritical, vPortExitCritical, xPortSetInterruptMask, BaseType_t, UBaseType_t,
+    };
+
+    /// There is a single core, like the kernel's default for `configNUM_CORES == 1`.
+    #[inline]
+    pub unsafe fn portYIELD_CORE(_xCoreID: BaseType_t) {
+        portYIELD()
+    }
+
+    /// Block the signals that simulate the interrupts, like the POSIX port's
+    /// `portDISABLE_INTERRUPTS`.
+    #[inline]
+    pub unsafe fn taskDISABLE_INTERRUPTS() {
+        vPortDisableInterrupts()
+    }
+
+    /// Unblock the signals that simulate the interrupts.
+    #[inline]
+    pub unsafe fn taskENABLE_INTERRUPTS() {
+        vPortEnableInterrupts()
+    }
+
+    #[inline]
+    pub unsafe fn portENTER_CRITICAL() {
+        vPortEnterCritical()
+    }
+
+    #[inline]
+    pub unsafe fn portEXIT_CRITICAL() {
+        vPortExitCritical()
+    }
+
+    #[inline]
+    pub unsafe fn portSET_INTERRUPT_MASK_FROM_ISR() -> UBaseType_t {
+        xPortSetInterruptMask()
+    }
+
+    #[inline]
+    pub unsafe fn portCLEAR_INTERRUPT_MASK_FROM_ISR(x: UBaseType_t) {
+        vPortClearInterruptMask(x)
+    }
+
+    /// Always zero, the simulated interrupts are signal handlers the kernel isn't told
+    /// about.
+    #[inline]
+    pub fn portCHECK_IF_IN_ISR() -> BaseType_t {
+        pdFALSE
+    }
+}
+
+#[cfg(feature = "static-alloc")]
+mod static_impl {
+    use crate::{
+        pdFALSE, pdTRUE, queueQUEUE_TYPE_BASE, queueQUEUE_TYPE_BINARY_SEMAPHORE,
+        queueQUEUE_TYPE_MUTEX, queueQUEUE_TYPE_RECURSIVE_MUTEX, semSEMAPHORE_QUEUE_ITEM_LENGTH,
+        xQueueCreateCountingSemaphoreStatic, xQueueCreateMutexStatic, xQueueGenericCreateStatic,
+        xStreamBufferGenericCreateStatic, MessageBufferHandle_t, QueueHandle_t, SemaphoreHandle_t,
+        StaticMessageBuffer_t, StaticQueue_t, StaticSemaphore_t, StaticStreamBuffer_t,
+        StreamBufferHandle_t, UBaseType_t,
+    };
+
+    #[inline]
+    pub unsafe fn xQueueCreateStatic(
+        uxQueueLength: UBaseType_t,
+        uxItemSize: UBaseType_t,
+        pucQueueStorage: *mut u8,
+        pxQueueBuffer: *mut StaticQueue_t,
+    ) -> QueueHandle_t {
+        xQueueGenericCreateStatic(
+            uxQueueLength,
+            uxItemSize,
+            pucQueueStorage,
+            pxQueueBuffer,
+            queueQUEUE_TYPE_BASE,
+        )
+    }
+
+    #[inline]
+    pub unsafe fn xSemaphoreCreateBinaryStatic(
+        pxStaticSemaphore: *mut StaticSemaphore_t,
+    ) -> SemaphoreHandle_t {
+        xQueueGenericCreateStatic(
+            1,
+            semSEMAPHORE_QUEUE_ITEM_LENGTH,
+            core::ptr::null_mut(),
+            pxStaticSemaphore,
+            queueQUEUE_TYPE_BINARY_SEMAPHORE,
+        )
+    }
+
+    #[inline]
+    pub unsafe fn xSemaphoreCreateMutexStatic(
+        pxMutexBuffer: *mut StaticSemaphore_t,
+    ) -> SemaphoreHandle_t {
+        xQueueCreateMutexStatic(queueQUEUE_TYPE_MUTEX, pxMutexBuffer)
+    }
+
+    #[inline]
+    pub unsafe fn xSemaphoreCreateRecursiveMutexStatic(
+        pxStaticSemaphore: *mut StaticSemaphore_t,
+    ) -> SemaphoreHandle_t {
+        xQueueCreateMutexStatic(queueQUEUE_TYPE_RECURSIVE_MUTEX, pxStaticSemaphore)
+    }
+
+    #[inline]
+    pub unsafe fn xSemaphoreCreateCountingStatic(
+        uxMaxCount: UBaseType_t,
+        uxInitialCount: UBaseType_t,
+        pxSemaphoreBuffer: *mut StaticSemaphore_t,
+    ) -> SemaphoreHandle_t {
+        xQueueCreateCountingSemaphoreStatic(uxMaxCount, uxInitialCount, pxSemaphoreBuffer)
+    }
+
+    #[inline]
+    pub unsafe fn xStreamBufferCreateStatic(
+        xBufferSizeBytes: usize,
+        xTriggerLevelBytes: usize,
+        pucStreamBufferStorageArea: *mut u8,
+        pxStaticStreamBuffer: *mut StaticStreamBuffer_t,
+    ) -> StreamBufferHandle_t {
+        xStreamBufferGenericCreateStatic(
+            xBufferSizeBytes,
+            xTriggerLevelBytes,
+            pdFALSE,
+            pucStreamBufferStorageArea,
+            pxStaticStreamBuffer,
+        )
+    }
+
+    #[inline]
+    pub unsafe fn xMessageBufferCreateStatic(
+        xBufferSizeBytes: usize,
+        pucMessageBufferStorageArea: *mut u8,
+        pxStaticMessageBuffer: *mut StaticMessageBuffer_t,
+    ) -> MessageBufferHandle_t {
+        xStreamBufferGenericCreateStatic(
+            xBufferSizeBytes,
+            0,
+            pdTRUE,
+            pucMessageBufferStorageArea,
+            pxStaticMessageBuffer,
+        ) as MessageBufferHandle_t
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ms_to_ticks() {
+        assert_eq!(pdMS_TO_TICKS(0), 0);
+        assert_eq!(pdMS_TO_TICKS(1000), configTICK_RATE_HZ);
+        // The multiplication wraps around like the 32 bit `TickType_t` one in C.
+        assert_eq!(pdMS_TO_TICKS(TickType_t::MAX / configTICK_RATE_HZ + 1), 0);
+    }
+
+    #[test]
+    fn valid_core_ids() {
+        assert_eq!(taskVALID_CORE_ID(-1), pdFALSE);
+        assert_eq!(taskVALID_CORE_ID(0), pdTRUE);
+        assert_eq!(taskVALID_CORE_ID(configNUM_CORES as BaseType_t - 1), pdTRUE);
+        assert_eq!(taskVALID_CORE_ID(configNUM_CORES as BaseType_t), pdFALSE);
+    }
+
+    /// Checks the wrappers against the kernel of the `host-sim` mode.
+    #[cfg(all(feature = "host-sim", feature = "use-rust-alloc"))]
+    mod kernel {
+        use core::mem::MaybeUninit;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        use chlorine::c_char;
+
+        use super::*;
+        use crate::{
+            portMAX_DELAY, sim, vEventGroupDelete, vTaskDelay, xEventGroupCreate,
+            xEventGroupSetBits, xQueueReceive, xTimerCreate, xTimerIsTimerActive,
+        };
+
+        unsafe fn receive(queue: QueueHandle_t) -> Option<u32> {
+            let mut item = MaybeUninit::<u32>::uninit();
+            if xQueueReceive(queue, item.as_mut_ptr() as *mut c_void, 0) == pdPASS {
+                Some(item.assume_init())
+            } else {
+                None
+            }
+        }
+
+        #[test]
+        fn queue_send_order() {
+            sim::run(|| unsafe {
+                let queue = xQueueCreate(3, 4);
+                assert!(!queue.is_null());
+                let send = |f: unsafe fn(_, _, _) -> _, item: u32| {
+                    f(queue, &item as *const u32 as *const c_void, 0)
+                };
+                assert_eq!(send(xQueueSend, 1), pdPASS);
+                assert_eq!(send(xQueueSendToFront, 2), pdPASS);
+                assert_eq!(send(xQueueSendToBack, 3), pdPASS);
+                assert_eq!(send(xQueueSend, 4), errQUEUE_FULL);
+
+                assert_eq!(receive(queue), Some(2));
+                assert_eq!(receive(queue), Some(1));
+                assert_eq!(receive(queue), Some(3));
+                assert_eq!(receive(queue), None);
+                vQueueDelete(queue);
+            });
+        }
+
+        #[test]
+        fn queue_overwrite() {
+            sim::run(|| unsafe {
+                let queue = xQueueCreate(1, 4);
+                for item in [5u32, 6] {
+                    let item = &item as *const u32 as *const c_void;
+                    assert_eq!(xQueueOverwrite(queue, item), pdPASS);
+                }
+                assert_eq!(uxQueueMessagesWaiting(queue), 1);
+                assert_eq!(receive(queue), Some(6));
+                vQueueDelete(queue);
+            });
+        }
+
+        #[test]
+        fn semaphore_counts() {
+            sim::run(|| unsafe {
+                let semaphore = xSemaphoreCreateCounting(2, 0);
+                assert_eq!(uxSemaphoreGetCount(semaphore), 0);
+                assert_eq!(xSemaphoreGive(semaphore), pdPASS);
+                assert_eq!(xSemaphoreGive(semaphore), pdPASS);
+                assert_eq!(xSemaphoreGive(semaphore), pdFAIL);
+                assert_eq!(uxSemaphoreGetCount(semaphore), 2);
+
+                assert_eq!(xSemaphoreTake(semaphore, 0), pdPASS);
+                assert_eq!(uxSemaphoreGetCount(semaphore), 1);
+                assert_eq!(xSemaphoreTake(semaphore, 0), pdPASS);
+                assert_eq!(xSemaphoreTake(semaphore, 0), pdFAIL);
+                vSemaphoreDelete(semaphore);
+
+                let binary = xSemaphoreCreateBinary();
+                assert_eq!(xSemaphoreTake(binary, 0), pdFAIL);
+                assert_eq!(xSemaphoreGive(binary), pdPASS);
+                assert_eq!(xSemaphoreGive(binary), pdFAIL);
+                assert_eq!(uxSemaphoreGetCount(binary), 1);
+                vSemaphoreDelete(binary);
+            });
+        }
+
+        #[test]
+        fn event_group_bits() {
+            sim::run(|| unsafe {
+                let group = xEventGroupCreate();
+                assert_eq!(xEventGroupGetBits(group), 0);
+                xEventGroupSetBits(group, 0b101);
+                assert_eq!(xEventGroupGetBits(group), 0b101);
+                xEventGroupClearBits(group, 0b001);
+                assert_eq!(xEventGroupGetBits(group), 0b100);
+                vEventGroupDelete(group);
+            });
+        }
+
+        static EXPIRED: AtomicUsize = AtomicUsize::new(0);
+
+        unsafe extern "C" fn count_expiry(_: TimerHandle_t) {
+            EXPIRED.fetch_add(1, Ordering::Relaxed);
+        }
+
+        #[test]
+        fn timer_start_stop() {
+            sim::run(|| unsafe {
+                let name = b"macros\0".as_ptr() as *const c_char;
+                let timer = xTimerCreate(
+                    name,
+                    1,
+                    pdTRUE as UBaseType_t,
+                    ptr::null_mut(),
+                    Some(count_expiry),
+                );
+                assert_eq!(xTimerIsTimerActive(timer), pdFALSE);
+
+                assert_eq!(xTimerStart(timer, portMAX_DELAY), pdPASS);
+                vTaskDelay(5);
+                assert_ne!(xTimerIsTimerActive(timer), pdFALSE);
+                assert!(EXPIRED.load(Ordering::Relaxed) > 0);
+
+                // The daemon has the priority of the test, let it process the command.
+                assert_eq!(xTimerStop(timer, portMAX_DELAY), pdPASS);
+                vTaskDelay(2);
+                assert_eq!(xTimerIsTimerActive(timer), pdFALSE);
+                let expired = EXPIRED.load(Ordering::Relaxed);
+                vTaskDelay(5);
+                assert_eq!(EXPIRED.load(Ordering::Relaxed), expired);
+                assert_eq!(xTimerDelete(timer, portMAX_DELAY), pdPASS);
+            });
+        }
+    }
+}
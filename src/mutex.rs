@@ -0,0 +1,613 @@
+//! Mutexes built on FreeRTOS mutex semaphores.
+//!
+//! FreeRTOS mutexes implement priority inheritance: a low priority task holding a
+//! mutex that a higher priority task waits on temporarily inherits the higher priority.
+//! As such, mutexes must always be given back by the task that took them and can't be
+//! used from ISRs.
+//!
+//! The mutex handles are created lazily on first use, so that [`Mutex::new`] and
+//! [`RecursiveMutex::new`] are `const` and can be used in `static` items. The first
+//! use panics if the kernel is out of memory for the handle. [`Mutex::try_new`] and
+//! [`RecursiveMutex::try_new`] create the handle right away and return the error
+//! instead. With the `static-alloc` feature, [`StaticMutex`] and [`StaticRecursiveMutex`]
+//! create the handle in memory that is part of the `static` item, which can't fail.
+
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::atomic;
+use crate::error::Error;
+use crate::task::Task;
+use crate::ticks::Ticks;
+use crate::{
+    pdTRUE, queueQUEUE_TYPE_MUTEX, queueQUEUE_TYPE_RECURSIVE_MUTEX, vQueueDelete,
+    xQueueCreateMutex, xQueueGenericSend, xQueueGetMutexHolder, xQueueGiveMutexRecursive,
+    xQueueSemaphoreTake, xQueueTakeMutexRecursive, QueueDefinition, QueueHandle_t,
+};
+
+#[cfg(feature = "static-alloc")]
+pub use static_impl::*;
+
+/// A lazily created mutex handle of the given queue type.
+struct LazyHandle<const TYPE: u8>(AtomicPtr<QueueDefinition>);
+
+impl<const TYPE: u8> LazyHandle<TYPE> {
+    const fn new() -> Self {
+        LazyHandle(AtomicPtr::new(ptr::null_mut()))
+    }
+
+    /// Create the handle right away.
+    fn try_new() -> Result<Self, Error> {
+        let handle = unsafe { xQueueCreateMutex(TYPE) };
+        if handle.is_null() {
+            return Err(Error::OutOfMemory);
+        }
+
+        Ok(LazyHandle(AtomicPtr::new(handle)))
+    }
+
+    /// Get the handle, creating it if it doesn't exist yet.
+    ///
+    /// Panics if the kernel is out of memory for the handle.
+    fn get(&self) -> QueueHandle_t {
+        let handle = self.0.load(Ordering::Acquire);
+        if !handle.is_null() {
+            return handle;
+        }
+
+        let new = unsafe { xQueueCreateMutex(TYPE) };
+        assert!(!new.is_null(), "could not allocate mutex");
+
+        match atomic::compare_exchange(&self.0, ptr::null_mut(), new) {
+            Ok(_) => new,
+            Err(existing) => {
+                // Another task created the mutex first.
+                unsafe { vQueueDelete(new) };
+                existing
+            }
+        }
+    }
+
+    fn holder(&self) -> Option<Task> {
+        let handle = self.0.load(Ordering::Acquire);
+        if handle.is_null() {
+            return None;
+        }
+
+        let holder = unsafe { xQueueGetMutexHolder(handle) };
+        if holder.is_null() {
+            None
+        } else {
+            Some(unsafe { Task::from_raw(holder) })
+        }
+    }
+}
+
+impl<const TYPE: u8> Drop for LazyHandle<TYPE> {
+    fn drop(&mut self) {
+        let handle = *self.0.get_mut();
+        if !handle.is_null() {
+            unsafe { vQueueDelete(handle) };
+        }
+    }
+}
+
+/// A raw FreeRTOS mutex, implementing [`lock_api::RawMutex`].
+pub struct RawMutex(LazyHandle<queueQUEUE_TYPE_MUTEX>);
+
+unsafe impl Send for RawMutex {}
+unsafe impl Sync for RawMutex {}
+
+impl RawMutex {
+    /// Create a new unlocked mutex, its handle is created on first use.
+    ///
+    /// Using the mutex panics if the kernel is out of memory for the handle.
+    pub const fn new() -> RawMutex {
+        RawMutex(LazyHandle::new())
+    }
+
+    /// Create a new unlocked mutex and its handle.
+    pub fn try_new() -> Result<RawMutex, Error> {
+        LazyHandle::try_new().map(RawMutex)
+    }
+
+    /// Get the raw FreeRTOS semaphore handle, creating it if it doesn't exist yet.
+    pub fn raw(&self) -> QueueHandle_t {
+        self.0.get()
+    }
+
+    /// Get the task that currently holds the mutex.
+    pub fn holder(&self) -> Option<Task> {
+        self.0.holder()
+    }
+
+    /// Try to take the mutex, waiting up to `timeout`.
+    pub fn take(&self, timeout: Ticks) -> bool {
+        unsafe { xQueueSemaphoreTake(self.0.get(), timeout.raw()) == pdTRUE }
+    }
+
+    /// Give the mutex back.
+    ///
+    /// # Safety
+    /// The mutex must be held by the current task.
+    pub unsafe fn give(&self) {
+        xQueueGenericSend(self.0.get(), ptr::null(), 0, crate::queueSEND_TO_BACK);
+    }
+}
+
+impl Default for RawMutex {
+    fn default() -> Self {
+        RawMutex::new()
+    }
+}
+
+unsafe impl lock_api::RawMutex for RawMutex {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const INIT: RawMutex = RawMutex::new();
+
+    // The mutex must be given back by the task that took it.
+    type GuardMarker = lock_api::GuardNoSend;
+
+    fn lock(&self) {
+        self.take(Ticks::MAX);
+    }
+
+    fn try_lock(&self) -> bool {
+        self.take(Ticks::ZERO)
+    }
+
+    unsafe fn unlock(&self) {
+        self.give()
+    }
+
+    fn is_locked(&self) -> bool {
+        self.holder().is_some()
+    }
+}
+
+unsafe impl lock_api::RawMutexTimed for RawMutex {
+    /// A relative timeout in ticks.
+    type Duration = Ticks;
+    /// An absolute tick count, as returned by `xTaskGetTickCount`.
+    type Instant = Ticks;
+
+    fn try_lock_for(&self, timeout: Ticks) -> bool {
+        self.take(timeout)
+    }
+
+    fn try_lock_until(&self, timeout: Ticks) -> bool {
+        self.take(Ticks::now().until(timeout))
+    }
+}
+
+/// A mutual exclusion primitive protecting `T`.
+pub struct Mutex<T: ?Sized> {
+    raw: RawMutex,
+    data: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for Mutex<T> {}
+unsafe impl<T: ?Sized + Send> Sync for Mutex<T> {}
+
+impl<T> Mutex<T> {
+    /// Create a new unlocked mutex protecting `data`, its handle is created on first
+    /// use.
+    ///
+    /// Locking panics if the kernel is out of memory for the handle.
+    pub const fn new(data: T) -> Mutex<T> {
+        Mutex {
+            raw: RawMutex::new(),
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    /// Create a new unlocked mutex protecting `data` and its handle.
+    pub fn try_new(data: T) -> Result<Mutex<T>, Error> {
+        Ok(Mutex {
+            raw: RawMutex::try_new()?,
+            data: UnsafeCell::new(data),
+        })
+    }
+
+    /// Consume the mutex, returning the protected data.
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+impl<T: ?Sized> Mutex<T> {
+    /// Lock the mutex, blocking until it is available.
+    pub fn lock(&self) -> MutexGuard<'_, T> {
+        self.raw.take(Ticks::MAX);
+        MutexGuard {
+            mutex: self,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Try to lock the mutex without blocking.
+    pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
+        self.try_lock_for(Ticks::ZERO)
+    }
+
+    /// Try to lock the mutex, waiting up to `timeout` for it to become available.
+    pub fn try_lock_for(&self, timeout: Ticks) -> Option<MutexGuard<'_, T>> {
+        if self.raw.take(timeout) {
+            Some(MutexGuard {
+                mutex: self,
+                _not_send: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Get the task that currently holds the mutex.
+    pub fn holder(&self) -> Option<Task> {
+        self.raw.holder()
+    }
+
+    /// Get the underlying raw mutex.
+    pub fn raw(&self) -> &RawMutex {
+        &self.raw
+    }
+
+    /// Get a mutable reference to the protected data.
+    ///
+    /// No locking is needed, as the mutable borrow guarantees exclusive access.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+}
+
+impl<T: ?Sized + Default> Default for Mutex<T> {
+    fn default() -> Self {
+        Mutex::new(T::default())
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for Mutex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.try_lock() {
+            Some(guard) => f.debug_struct("Mutex").field("data", &&*guard).finish(),
+            None => f.write_str("Mutex { <locked> }"),
+        }
+    }
+}
+
+/// An RAII guard that gives the mutex back when dropped.
+pub struct MutexGuard<'a, T: ?Sized> {
+    mutex: &'a Mutex<T>,
+    // The guard must be dropped by the task that locked the mutex, so it is not `Send`.
+    _not_send: PhantomData<*const ()>,
+}
+
+unsafe impl<T: ?Sized + Sync> Sync for MutexGuard<'_, T> {}
+
+impl<T: ?Sized> Deref for MutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.mutex.data.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for MutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.mutex.data.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for MutexGuard<'_, T> {
+    fn drop(&mut self) {
+        unsafe { self.mutex.raw.give() }
+    }
+}
+
+/// A raw FreeRTOS recursive mutex.
+///
+/// A recursive mutex can be taken multiple times by the task holding it and is only
+/// released once it was given back as often as it was taken.
+pub struct RawRecursiveMutex(LazyHandle<queueQUEUE_TYPE_RECURSIVE_MUTEX>);
+
+unsafe impl Send for RawRecursiveMutex {}
+unsafe impl Sync for RawRecursiveMutex {}
+
+impl RawRecursiveMutex {
+    /// Create a new unlocked recursive mutex, its handle is created on first use.
+    ///
+    /// Using the mutex panics if the kernel is out of memory for the handle.
+    pub const fn new() -> RawRecursiveMutex {
+        RawRecursiveMutex(LazyHandle::new())
+    }
+
+    /// Create a new unlocked recursive mutex and its handle.
+    pub fn try_new() -> Result<RawRecursiveMutex, Error> {
+        LazyHandle::try_new().map(RawRecursiveMutex)
+    }
+
+    /// Get the raw FreeRTOS semaphore handle, creating it if it doesn't exist yet.
+    pub fn raw(&self) -> QueueHandle_t {
+        self.0.get()
+    }
+
+    /// Get the task that currently holds the mutex.
+    pub fn holder(&self) -> Option<Task> {
+        self.0.holder()
+    }
+
+    /// Try to take the mutex, waiting up to `timeout`.
+    pub fn take(&self, timeout: Ticks) -> bool {
+        unsafe { xQueueTakeMutexRecursive(self.0.get(), timeout.raw()) == pdTRUE }
+    }
+
+    /// Give the mutex back once.
+    ///
+    /// # Safety
+    /// The mutex must be held by the current task.
+    pub unsafe fn give(&self) {
+        xQueueGiveMutexRecursive(self.0.get());
+    }
+}
+
+impl Default for RawRecursiveMutex {
+    fn default() -> Self {
+        RawRecursiveMutex::new()
+    }
+}
+
+/// A recursive mutual exclusion primitive protecting `T`.
+///
+/// The task holding the lock can lock it again, hence the guards only give shared
+/// access to the data. Use a `Cell` or `RefCell` for interior mutability.
+pub struct RecursiveMutex<T: ?Sized> {
+    raw: RawRecursiveMutex,
+    data: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for RecursiveMutex<T> {}
+unsafe impl<T: ?Sized + Send> Sync for RecursiveMutex<T> {}
+
+impl<T> RecursiveMutex<T> {
+    /// Create a new unlocked recursive mutex protecting `data`, its handle is created on
+    /// first use.
+    ///
+    /// Locking panics if the kernel is out of memory for the handle.
+    pub const fn new(data: T) -> RecursiveMutex<T> {
+        RecursiveMutex {
+            raw: RawRecursiveMutex::new(),
+            data: UnsafeCell::new(data),
+        }
+    }
+
+    /// Create a new unlocked recursive mutex protecting `data` and its handle.
+    pub fn try_new(data: T) -> Result<RecursiveMutex<T>, Error> {
+        Ok(RecursiveMutex {
+            raw: RawRecursiveMutex::try_new()?,
+            data: UnsafeCell::new(data),
+        })
+    }
+
+    /// Consume the mutex, returning the protected data.
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+impl<T: ?Sized> RecursiveMutex<T> {
+    /// Lock the mutex, blocking until it is available.
+    pub fn lock(&self) -> RecursiveMutexGuard<'_, T> {
+        self.raw.take(Ticks::MAX);
+        RecursiveMutexGuard {
+            mutex: self,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Try to lock the mutex without blocking.
+    pub fn try_lock(&self) -> Option<RecursiveMutexGuard<'_, T>> {
+        self.try_lock_for(Ticks::ZERO)
+    }
+
+    /// Try to lock the mutex, waiting up to `timeout` for it to become available.
+    pub fn try_lock_for(&self, timeout: Ticks) -> Option<RecursiveMutexGuard<'_, T>> {
+        if self.raw.take(timeout) {
+            Some(RecursiveMutexGuard {
+                mutex: self,
+                _not_send: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Get the task that currently holds the mutex.
+    pub fn holder(&self) -> Option<Task> {
+        self.raw.holder()
+    }
+
+    /// Get the underlying raw recursive mutex.
+    pub fn raw(&self) -> &RawRecursiveMutex {
+        &self.raw
+    }
+
+    /// Get a mutable reference to the protected data.
+    ///
+    /// No locking is needed, as the mutable borrow guarantees exclusive access.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+}
+
+impl<T: ?Sized + Default> Default for RecursiveMutex<T> {
+    fn default() -> Self {
+        RecursiveMutex::new(T::default())
+    }
+}
+
+/// An RAII guard that gives the recursive mutex back once when dropped.
+pub struct RecursiveMutexGuard<'a, T: ?Sized> {
+    mutex: &'a RecursiveMutex<T>,
+    _not_send: PhantomData<*const ()>,
+}
+
+unsafe impl<T: ?Sized + Sync> Sync for RecursiveMutexGuard<'_, T> {}
+
+impl<T: ?Sized> Deref for RecursiveMutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.mutex.data.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for RecursiveMutexGuard<'_, T> {
+    fn drop(&mut self) {
+        unsafe { self.mutex.raw.give() }
+    }
+}
+
+#[cfg(feature = "static-alloc")]
+mod static_impl {
+    use core::cell::UnsafeCell;
+    use core::mem::MaybeUninit;
+    use core::sync::atomic::Ordering;
+
+    use super::{LazyHandle, Mutex, RecursiveMutex};
+    use crate::atomic::Once;
+    use crate::{xQueueCreateMutexStatic, StaticSemaphore_t};
+
+    /// The memory of a mutex handle.
+    struct StaticHandle {
+        buffer: UnsafeCell<MaybeUninit<StaticSemaphore_t>>,
+        created: Once,
+    }
+
+    impl StaticHandle {
+        const fn new() -> StaticHandle {
+            StaticHandle {
+                buffer: UnsafeCell::new(MaybeUninit::uninit()),
+                created: Once::new(),
+            }
+        }
+
+        /// Create the handle of `lazy` in this memory, before it is first used.
+        fn create<const TYPE: u8>(&'static self, lazy: &LazyHandle<TYPE>) {
+            self.created.call_once(|| {
+                // Can't fail, as all memory is provided.
+                let handle = unsafe {
+                    xQueueCreateMutexStatic(TYPE, self.buffer.get() as *mut StaticSemaphore_t)
+                };
+                lazy.0.store(handle, Ordering::Release);
+            });
+        }
+    }
+
+    /// A [`Mutex`] whose handle is part of the `static` item it is placed in.
+    ///
+    /// The handle is created on the first call to [`StaticMutex::get`].
+    ///
+    /// ```ignore
+    /// static STATE: StaticMutex<State> = StaticMutex::new(State::Idle);
+    ///
+    /// *STATE.get().lock() = State::Running;
+    /// ```
+    pub struct StaticMutex<T> {
+        memory: StaticHandle,
+        mutex: Mutex<T>,
+    }
+
+    unsafe impl<T: Send> Sync for StaticMutex<T> {}
+
+    impl<T> StaticMutex<T> {
+        /// Create an unlocked mutex protecting `data`, with uninitialized handle memory.
+        pub const fn new(data: T) -> StaticMutex<T> {
+            StaticMutex {
+                memory: StaticHandle::new(),
+                mutex: Mutex::new(data),
+            }
+        }
+
+        /// Get the mutex, creating its handle on the first call.
+        pub fn get(&'static self) -> &'static Mutex<T> {
+            self.memory.create(&self.mutex.raw.0);
+            &self.mutex
+        }
+    }
+
+    /// A [`RecursiveMutex`] whose handle is part of the `static` item it is placed in.
+    ///
+    /// The handle is created on the first call to [`StaticRecursiveMutex::get`].
+    pub struct StaticRecursiveMutex<T> {
+        memory: StaticHandle,
+        mutex: RecursiveMutex<T>,
+    }
+
+    unsafe impl<T: Send> Sync for StaticRecursiveMutex<T> {}
+
+    impl<T> StaticRecursiveMutex<T> {
+        /// Create an unlocked recursive mutex protecting `data`, with uninitialized
+        /// handle memory.
+        pub const fn new(data: T) -> StaticRecursiveMutex<T> {
+            StaticRecursiveMutex {
+                memory: StaticHandle::new(),
+                mutex: RecursiveMutex::new(data),
+            }
+        }
+
+        /// Get the recursive mutex, creating its handle on the first call.
+        pub fn get(&'static self) -> &'static RecursiveMutex<T> {
+            self.memory.create(&self.mutex.raw.0);
+            &self.mutex
+        }
+    }
+}
+
+#[cfg(all(test, feature = "host-sim", feature = "use-rust-alloc"))]
+mod tests {
+    extern crate std;
+    use std::sync::Arc;
+
+    use super::{Mutex, RecursiveMutex};
+    use crate::sim;
+    use crate::task::{spawn, Task};
+    use crate::ticks::Ticks;
+
+    #[test]
+    fn lock_excludes_other_tasks() {
+        sim::run(|| {
+            let mutex = Arc::new(Mutex::new(0));
+
+            let guard = mutex.lock();
+            assert_eq!(mutex.holder(), Some(Task::current()));
+            let other = mutex.clone();
+            let locked = spawn(move || other.try_lock_for(Ticks::new(2)).is_some()).unwrap();
+            assert!(!locked.join());
+            drop(guard);
+            assert_eq!(mutex.holder(), None);
+
+            let other = mutex.clone();
+            spawn(move || *other.lock() += 1).unwrap().join();
+            assert_eq!(*mutex.lock(), 1);
+        });
+    }
+
+    #[test]
+    fn recursive_lock() {
+        sim::run(|| {
+            let mutex = RecursiveMutex::new(5);
+
+            let outer = mutex.lock();
+            let inner = mutex.try_lock().unwrap();
+            assert_eq!(*inner, 5);
+            drop(inner);
+            assert_eq!(mutex.holder(), Some(Task::current()));
+            drop(outer);
+            assert_eq!(mutex.holder(), None);
+        });
+    }
+}
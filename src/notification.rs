@@ -0,0 +1,275 @@
+//! Direct-to-task notifications.
+//!
+//! Every task has `configTASK_NOTIFICATION_ARRAY_ENTRIES` notification slots (set with
+//! the `FREERTOS_TASK_NOTIFICATION_ARRAY_ENTRIES` build variable), each holding a 32-bit
+//! value and a pending state. Indices past the last slot panic. Other tasks and ISRs
+//! update a slot through a [`Notification`] (see [`Task::notification`]), while the
+//! task itself waits on its own slots with [`wait`] or [`take`].
+
+use core::ptr;
+
+use crate::error::Error;
+use crate::task::Task;
+use crate::ticks::Ticks;
+use crate::{
+    configTASK_NOTIFICATION_ARRAY_ENTRIES, eNotifyAction, eNotifyAction_eIncrement,
+    eNotifyAction_eNoAction, eNotifyAction_eSetBits, eNotifyAction_eSetValueWithOverwrite,
+    eNotifyAction_eSetValueWithoutOverwrite, pdFALSE, pdPASS, pdTRUE, tskDEFAULT_INDEX_TO_NOTIFY,
+    ulTaskGenericNotifyTake, ulTaskGenericNotifyValueClear, vTaskGenericNotifyGiveFromISR,
+    xTaskGenericNotify, xTaskGenericNotifyFromISR, xTaskGenericNotifyStateClear,
+    xTaskGenericNotifyWait, BaseType_t, UBaseType_t,
+};
+
+/// The notification slot used by the non-indexed FreeRTOS API.
+pub const DEFAULT_INDEX: usize = tskDEFAULT_INDEX_TO_NOTIFY as usize;
+
+fn check_index(index: usize) -> UBaseType_t {
+    assert!(
+        index < configTASK_NOTIFICATION_ARRAY_ENTRIES as usize,
+        "notification index out of range"
+    );
+    index as UBaseType_t
+}
+
+/// How a notification updates the value of the notified slot (`eNotifyAction`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotifyAction {
+    /// Only mark the notification as pending, leaving the value unchanged.
+    NoAction,
+    /// Bitwise OR the value with the given bits.
+    SetBits(u32),
+    /// Increment the value by one.
+    Increment,
+    /// Set the value, even if the previous notification wasn't received yet.
+    SetValueWithOverwrite(u32),
+    /// Set the value, but only if no notification is pending.
+    SetValueWithoutOverwrite(u32),
+}
+
+impl NotifyAction {
+    fn to_raw(self) -> (eNotifyAction, u32) {
+        match self {
+            NotifyAction::NoAction => (eNotifyAction_eNoAction, 0),
+            NotifyAction::SetBits(bits) => (eNotifyAction_eSetBits, bits),
+            NotifyAction::Increment => (eNotifyAction_eIncrement, 0),
+            NotifyAction::SetValueWithOverwrite(value) => {
+                (eNotifyAction_eSetValueWithOverwrite, value)
+            }
+            NotifyAction::SetValueWithoutOverwrite(value) => {
+                (eNotifyAction_eSetValueWithoutOverwrite, value)
+            }
+        }
+    }
+}
+
+/// A notification slot of a task.
+///
+/// The `*_from_isr` variants additionally return whether a higher priority task was
+/// woken, in which case the ISR should yield (see [`vPortYieldFromISR`]) before it
+/// returns.
+///
+/// [`vPortYieldFromISR`]: crate::vPortYieldFromISR
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Notification {
+    task: Task,
+    index: UBaseType_t,
+}
+
+impl Task {
+    /// Get the notification slot `index` of this task.
+    ///
+    /// Panics if `index` isn't less than `configTASK_NOTIFICATION_ARRAY_ENTRIES`.
+    ///
+    /// # Safety
+    /// The task must not be deleted while the returned [`Notification`] is in use.
+    pub unsafe fn notification(&self, index: usize) -> Notification {
+        Notification {
+            task: *self,
+            index: check_index(index),
+        }
+    }
+}
+
+impl Notification {
+    /// The task this slot belongs to.
+    pub fn task(&self) -> Task {
+        self.task
+    }
+
+    /// The index of the slot.
+    pub fn index(&self) -> usize {
+        self.index as usize
+    }
+
+    /// Notify the task, updating the slot according to `action`.
+    ///
+    /// Fails with [`Error::NotificationPending`] if `action` is
+    /// [`NotifyAction::SetValueWithoutOverwrite`] and a notification is still pending.
+    pub fn notify(&self, action: NotifyAction) -> Result<(), Error> {
+        self.notify_and_query(action).map(|_| ())
+    }
+
+    /// Like [`Notification::notify`], but returns the value of the slot before it was
+    /// updated.
+    pub fn notify_and_query(&self, action: NotifyAction) -> Result<u32, Error> {
+        let (action, value) = action.to_raw();
+        let mut previous = 0;
+        let res = unsafe {
+            xTaskGenericNotify(
+                self.task.raw(),
+                self.index,
+                value,
+                action,
+                &mut previous as *mut _,
+            )
+        };
+
+        if res == pdPASS {
+            Ok(previous)
+        } else {
+            Err(Error::NotificationPending)
+        }
+    }
+
+    /// Notify the task from an ISR, updating the slot according to `action`.
+    ///
+    /// Returns whether a higher priority task was woken.
+    pub fn notify_from_isr(&self, action: NotifyAction) -> Result<bool, Error> {
+        let (action, value) = action.to_raw();
+        let mut woken: BaseType_t = pdFALSE;
+        let res = unsafe {
+            xTaskGenericNotifyFromISR(
+                self.task.raw(),
+                self.index,
+                value,
+                action,
+                ptr::null_mut(),
+                &mut woken as *mut _,
+            )
+        };
+
+        if res == pdPASS {
+            Ok(woken != pdFALSE)
+        } else {
+            Err(Error::NotificationPending)
+        }
+    }
+
+    /// Increment the value of the slot, using it as a counting semaphore that the task
+    /// receives with [`take`] (`xTaskNotifyGive`).
+    pub fn give(&self) {
+        let _ = self.notify(NotifyAction::Increment);
+    }
+
+    /// Increment the value of the slot from an ISR (`vTaskNotifyGiveFromISR`).
+    ///
+    /// Returns whether a higher priority task was woken.
+    pub fn give_from_isr(&self) -> bool {
+        let mut woken: BaseType_t = pdFALSE;
+        unsafe {
+            vTaskGenericNotifyGiveFromISR(self.task.raw(), self.index, &mut woken as *mut _);
+        }
+        woken != pdFALSE
+    }
+
+    /// Clear the pending state of the slot without changing its value.
+    ///
+    /// Returns whether a notification was pending.
+    pub fn clear_state(&self) -> bool {
+        unsafe { xTaskGenericNotifyStateClear(self.task.raw(), self.index) == pdTRUE }
+    }
+
+    /// Clear `bits` in the value of the slot, returning the value before it was cleared.
+    pub fn clear_bits(&self, bits: u32) -> u32 {
+        unsafe { ulTaskGenericNotifyValueClear(self.task.raw(), self.index, bits) }
+    }
+}
+
+/// Wait up to `timeout` for a notification on slot `index` of the current task.
+///
+/// The bits `clear_on_entry` are cleared in the value before waiting (if no
+/// notification is pending already), `clear_on_exit` after a notification was
+/// received. Returns the value of the slot before it was cleared on exit.
+///
+/// Panics if `index` isn't less than `configTASK_NOTIFICATION_ARRAY_ENTRIES`.
+pub fn wait(
+    index: usize,
+    clear_on_entry: u32,
+    clear_on_exit: u32,
+    timeout: Ticks,
+) -> Result<u32, Error> {
+    let index = check_index(index);
+    let mut value = 0;
+    let res = unsafe {
+        xTaskGenericNotifyWait(
+            index,
+            clear_on_entry,
+            clear_on_exit,
+            &mut value as *mut _,
+            timeout.raw(),
+        )
+    };
+
+    if res == pdTRUE {
+        Ok(value)
+    } else {
+        Err(Error::Timeout)
+    }
+}
+
+/// Wait up to `timeout` for the value of slot `index` of the current task to become
+/// non-zero, using it as a binary (`clear_on_exit`) or counting semaphore.
+///
+/// Returns the value before it was decremented or cleared, i.e. zero on timeout. Panics
+/// if `index` isn't less than `configTASK_NOTIFICATION_ARRAY_ENTRIES`.
+pub fn take(index: usize, clear_on_exit: bool, timeout: Ticks) -> u32 {
+    let index = check_index(index);
+    unsafe {
+        ulTaskGenericNotifyTake(
+            index,
+            if clear_on_exit { pdTRUE } else { pdFALSE },
+            timeout.raw(),
+        )
+    }
+}
+
+#[cfg(all(test, feature = "host-sim", feature = "use-rust-alloc"))]
+mod tests {
+    use super::{take, wait, NotifyAction, DEFAULT_INDEX};
+    use crate::error::Error;
+    use crate::sim;
+    use crate::task::{spawn, Task};
+    use crate::ticks::Ticks;
+
+    #[test]
+    fn notify_another_task() {
+        sim::run(|| {
+            let main = Task::current();
+            let handle = spawn(move || {
+                let slot = unsafe { main.notification(DEFAULT_INDEX) };
+                slot.notify(NotifyAction::SetBits(0b100)).unwrap();
+                slot.notify(NotifyAction::SetBits(0b001)).unwrap();
+                slot.notify(NotifyAction::SetValueWithoutOverwrite(7))
+            })
+            .unwrap();
+
+            assert_eq!(handle.join(), Err(Error::NotificationPending));
+            assert_eq!(wait(DEFAULT_INDEX, 0, u32::MAX, Ticks::MAX), Ok(0b101));
+            assert_eq!(wait(DEFAULT_INDEX, 0, 0, Ticks::ZERO), Err(Error::Timeout));
+        });
+    }
+
+    #[test]
+    fn give_and_take() {
+        sim::run(|| {
+            let slot = unsafe { Task::current().notification(DEFAULT_INDEX) };
+            slot.give();
+            slot.give();
+            slot.give();
+
+            assert_eq!(take(DEFAULT_INDEX, false, Ticks::ZERO), 3);
+            assert_eq!(take(DEFAULT_INDEX, true, Ticks::ZERO), 2);
+            assert_eq!(take(DEFAULT_INDEX, true, Ticks::ZERO), 0);
+        });
+    }
+}
@@ -0,0 +1,344 @@
+//! Typed FreeRTOS queues.
+
+use core::marker::PhantomData;
+use core::mem::{self, MaybeUninit};
+
+use chlorine::c_void;
+
+use crate::error::Error;
+use crate::ticks::Ticks;
+use crate::{
+    pdFALSE, pdTRUE, queueOVERWRITE, queueQUEUE_TYPE_BASE, queueSEND_TO_BACK, queueSEND_TO_FRONT,
+    uxQueueMessagesWaiting, uxQueueMessagesWaitingFromISR, uxQueueSpacesAvailable, vQueueDelete,
+    xQueueGenericCreate, xQueueGenericReset, xQueueGenericSend, xQueueGenericSendFromISR,
+    xQueuePeek, xQueuePeekFromISR, xQueueReceive, xQueueReceiveFromISR, BaseType_t, QueueHandle_t,
+    UBaseType_t,
+};
+
+#[cfg(feature = "static-alloc")]
+pub use static_impl::*;
+
+/// A FreeRTOS queue holding items of type `T`.
+///
+/// Items are copied into and out of the queue, hence `T: Copy`. The `*_from_isr`
+/// variants never block and additionally return whether a higher priority task was
+/// woken, in which case the ISR should yield (see [`vPortYieldFromISR`]) before it
+/// returns.
+///
+/// [`vPortYieldFromISR`]: crate::vPortYieldFromISR
+pub struct Queue<T: Copy + Send> {
+    handle: QueueHandle_t,
+    _marker: PhantomData<T>,
+}
+
+unsafe impl<T: Copy + Send> Send for Queue<T> {}
+unsafe impl<T: Copy + Send> Sync for Queue<T> {}
+
+impl<T: Copy + Send> Queue<T> {
+    /// Create a new queue that can hold up to `length` items.
+    ///
+    /// Fails with [`Error::InvalidArgument`] if `length` is zero, which the kernel
+    /// rejects.
+    pub fn new(length: usize) -> Result<Queue<T>, Error> {
+        if length == 0 {
+            return Err(Error::InvalidArgument);
+        }
+
+        let handle = unsafe {
+            xQueueGenericCreate(
+                length as UBaseType_t,
+                mem::size_of::<T>() as UBaseType_t,
+                queueQUEUE_TYPE_BASE,
+            )
+        };
+        if handle.is_null() {
+            return Err(Error::OutOfMemory);
+        }
+
+        Ok(Queue {
+            handle,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Get the raw FreeRTOS queue handle.
+    pub fn raw(&self) -> QueueHandle_t {
+        self.handle
+    }
+
+    /// Send `item` to the back of the queue, waiting up to `timeout` for space.
+    pub fn send(&self, item: T, timeout: Ticks) -> Result<(), Error> {
+        self.generic_send(item, timeout, queueSEND_TO_BACK)
+    }
+
+    /// Send `item` to the front of the queue, waiting up to `timeout` for space.
+    pub fn send_to_front(&self, item: T, timeout: Ticks) -> Result<(), Error> {
+        self.generic_send(item, timeout, queueSEND_TO_FRONT)
+    }
+
+    /// Write `item` to the queue, even if it is full.
+    ///
+    /// Only meant for queues with a length of one, the kernel asserts otherwise.
+    pub fn overwrite(&self, item: T) {
+        // Always succeeds, as the item is overwritten if the queue is full.
+        let _ = self.generic_send(item, Ticks::ZERO, queueOVERWRITE);
+    }
+
+    /// Receive an item from the front of the queue, waiting up to `timeout` for one.
+    pub fn receive(&self, timeout: Ticks) -> Result<T, Error> {
+        let mut item = MaybeUninit::<T>::uninit();
+        let res =
+            unsafe { xQueueReceive(self.handle, item.as_mut_ptr() as *mut c_void, timeout.raw()) };
+
+        if res == pdTRUE {
+            Ok(unsafe { item.assume_init() })
+        } else {
+            Err(Error::QueueEmpty)
+        }
+    }
+
+    /// Get a copy of the item at the front of the queue without removing it, waiting
+    /// up to `timeout` for one.
+    pub fn peek(&self, timeout: Ticks) -> Result<T, Error> {
+        let mut item = MaybeUninit::<T>::uninit();
+        let res =
+            unsafe { xQueuePeek(self.handle, item.as_mut_ptr() as *mut c_void, timeout.raw()) };
+
+        if res == pdTRUE {
+            Ok(unsafe { item.assume_init() })
+        } else {
+            Err(Error::QueueEmpty)
+        }
+    }
+
+    /// Send `item` to the back of the queue from an ISR.
+    ///
+    /// Returns whether a higher priority task was woken.
+    pub fn send_from_isr(&self, item: T) -> Result<bool, Error> {
+        self.generic_send_from_isr(item, queueSEND_TO_BACK)
+    }
+
+    /// Send `item` to the front of the queue from an ISR.
+    ///
+    /// Returns whether a higher priority task was woken.
+    pub fn send_to_front_from_isr(&self, item: T) -> Result<bool, Error> {
+        self.generic_send_from_isr(item, queueSEND_TO_FRONT)
+    }
+
+    /// Write `item` to the queue from an ISR, even if it is full.
+    ///
+    /// Returns whether a higher priority task was woken.
+    pub fn overwrite_from_isr(&self, item: T) -> bool {
+        self.generic_send_from_isr(item, queueOVERWRITE)
+            .unwrap_or(false)
+    }
+
+    /// Receive an item from the front of the queue from an ISR.
+    ///
+    /// Returns the item and whether a higher priority task was woken.
+    pub fn receive_from_isr(&self) -> Result<(T, bool), Error> {
+        let mut item = MaybeUninit::<T>::uninit();
+        let mut woken: BaseType_t = pdFALSE;
+        let res = unsafe {
+            xQueueReceiveFromISR(
+                self.handle,
+                item.as_mut_ptr() as *mut c_void,
+                &mut woken as *mut _,
+            )
+        };
+
+        if res == pdTRUE {
+            Ok((unsafe { item.assume_init() }, woken != pdFALSE))
+        } else {
+            Err(Error::QueueEmpty)
+        }
+    }
+
+    /// Get a copy of the item at the front of the queue from an ISR without removing
+    /// it.
+    pub fn peek_from_isr(&self) -> Result<T, Error> {
+        let mut item = MaybeUninit::<T>::uninit();
+        let res = unsafe { xQueuePeekFromISR(self.handle, item.as_mut_ptr() as *mut c_void) };
+
+        if res == pdTRUE {
+            Ok(unsafe { item.assume_init() })
+        } else {
+            Err(Error::QueueEmpty)
+        }
+    }
+
+    /// The number of items in the queue.
+    pub fn len(&self) -> usize {
+        unsafe { uxQueueMessagesWaiting(self.handle) as usize }
+    }
+
+    /// The number of items in the queue, callable from an ISR.
+    pub fn len_from_isr(&self) -> usize {
+        unsafe { uxQueueMessagesWaitingFromISR(self.handle) as usize }
+    }
+
+    /// Whether the queue is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of free spaces in the queue.
+    pub fn spaces_available(&self) -> usize {
+        unsafe { uxQueueSpacesAvailable(self.handle) as usize }
+    }
+
+    /// Remove all items from the queue.
+    pub fn reset(&self) {
+        unsafe {
+            xQueueGenericReset(self.handle, pdFALSE);
+        }
+    }
+
+    fn generic_send(&self, item: T, timeout: Ticks, position: BaseType_t) -> Result<(), Error> {
+        let res = unsafe {
+            xQueueGenericSend(
+                self.handle,
+                &item as *const T as *const c_void,
+                timeout.raw(),
+                position,
+            )
+        };
+
+        if res == pdTRUE {
+            Ok(())
+        } else {
+            Err(Error::QueueFull)
+        }
+    }
+
+    fn generic_send_from_isr(&self, item: T, position: BaseType_t) -> Result<bool, Error> {
+        let mut woken: BaseType_t = pdFALSE;
+        let res = unsafe {
+            xQueueGenericSendFromISR(
+                self.handle,
+                &item as *const T as *const c_void,
+                &mut woken as *mut _,
+                position,
+            )
+        };
+
+        if res == pdTRUE {
+            Ok(woken != pdFALSE)
+        } else {
+            Err(Error::QueueFull)
+        }
+    }
+}
+
+impl<T: Copy + Send> Drop for Queue<T> {
+    fn drop(&mut self) {
+        unsafe { vQueueDelete(self.handle) }
+    }
+}
+
+#[cfg(feature = "static-alloc")]
+mod static_impl {
+    use core::cell::UnsafeCell;
+    use core::marker::PhantomData;
+    use core::mem::{self, MaybeUninit};
+    use core::ptr;
+
+    use super::Queue;
+    use crate::atomic::Once;
+    use crate::{queueQUEUE_TYPE_BASE, xQueueGenericCreateStatic, StaticQueue_t, UBaseType_t};
+
+    /// A queue of up to `N` items whose memory is part of the `static` item it is placed
+    /// in.
+    ///
+    /// The queue is created on the first call to [`StaticQueue::get`].
+    ///
+    /// ```ignore
+    /// static EVENTS: StaticQueue<Event, 8> = StaticQueue::new();
+    ///
+    /// EVENTS.get().send(Event::Start, Ticks::MAX)?;
+    /// ```
+    pub struct StaticQueue<T: Copy + Send, const N: usize> {
+        storage: UnsafeCell<MaybeUninit<[T; N]>>,
+        buffer: UnsafeCell<MaybeUninit<StaticQueue_t>>,
+        queue: Once<Queue<T>>,
+    }
+
+    unsafe impl<T: Copy + Send, const N: usize> Sync for StaticQueue<T, N> {}
+
+    impl<T: Copy + Send, const N: usize> Default for StaticQueue<T, N> {
+        fn default() -> Self {
+            StaticQueue::new()
+        }
+    }
+
+    impl<T: Copy + Send, const N: usize> StaticQueue<T, N> {
+        /// Create the uninitialized queue memory.
+        ///
+        /// Panics (at compile time in a `static` item) if `N` is zero.
+        pub const fn new() -> StaticQueue<T, N> {
+            assert!(N > 0, "the queue length must not be zero");
+            StaticQueue {
+                storage: UnsafeCell::new(MaybeUninit::uninit()),
+                buffer: UnsafeCell::new(MaybeUninit::uninit()),
+                queue: Once::new(),
+            }
+        }
+
+        /// Get the queue, creating it on the first call.
+        pub fn get(&'static self) -> &'static Queue<T> {
+            // The kernel rejects storage for zero-sized items.
+            let storage = if mem::size_of::<T>() == 0 {
+                ptr::null_mut()
+            } else {
+                self.storage.get() as *mut u8
+            };
+            self.queue.call_once(|| Queue {
+                // Can't fail, as all memory is provided and `new` checked the length.
+                handle: unsafe {
+                    xQueueGenericCreateStatic(
+                        N as UBaseType_t,
+                        mem::size_of::<T>() as UBaseType_t,
+                        storage,
+                        self.buffer.get() as *mut StaticQueue_t,
+                        queueQUEUE_TYPE_BASE,
+                    )
+                },
+                _marker: PhantomData,
+            })
+        }
+    }
+}
+
+#[cfg(all(test, feature = "host-sim"))]
+mod tests {
+    use super::Queue;
+    use crate::error::Error;
+    use crate::sim;
+    use crate::ticks::Ticks;
+
+    #[test]
+    fn send_and_receive() {
+        sim::run(|| {
+            let queue = Queue::<u32>::new(2).unwrap();
+            assert!(queue.is_empty());
+
+            queue.send(1, Ticks::ZERO).unwrap();
+            queue.send_to_front(2, Ticks::ZERO).unwrap();
+            assert_eq!(queue.send(3, Ticks::ZERO), Err(Error::QueueFull));
+            assert_eq!(queue.len(), 2);
+            assert_eq!(queue.spaces_available(), 0);
+
+            assert_eq!(queue.peek(Ticks::ZERO), Ok(2));
+            assert_eq!(queue.receive(Ticks::ZERO), Ok(2));
+            assert_eq!(queue.receive(Ticks::ZERO), Ok(1));
+            assert_eq!(queue.receive(Ticks::ZERO), Err(Error::QueueEmpty));
+        });
+    }
+
+    #[test]
+    fn zero_length_is_rejected() {
+        sim::run(|| {
+            assert_eq!(Queue::<u32>::new(0).err(), Some(Error::InvalidArgument));
+        });
+    }
+}
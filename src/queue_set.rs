@@ -0,0 +1,279 @@
+//! Queue sets for waiting on multiple queues and semaphores at once.
+
+use core::marker::PhantomData;
+
+use crate::error::Error;
+use crate::queue::Queue;
+use crate::semaphore::{BinarySemaphore, CountingSemaphore, Semaphore};
+use crate::ticks::Ticks;
+use crate::{
+    pdPASS, vQueueDelete, xQueueAddToSet, xQueueCreateSet, xQueueRemoveFromSet,
+    xQueueSelectFromSet, xQueueSelectFromSetFromISR, QueueSetHandle_t, QueueSetMemberHandle_t,
+    UBaseType_t,
+};
+
+/// A queue or semaphore that can be added to a [`QueueSet`].
+///
+/// # Safety
+/// [`QueueSetMember::member_handle`] must return a valid queue or semaphore handle
+/// that lives as long as `self`.
+pub unsafe trait QueueSetMember {
+    /// Get the raw handle of the queue or semaphore.
+    fn member_handle(&self) -> QueueSetMemberHandle_t;
+}
+
+unsafe impl<T: Copy + Send> QueueSetMember for Queue<T> {
+    fn member_handle(&self) -> QueueSetMemberHandle_t {
+        self.raw()
+    }
+}
+
+unsafe impl QueueSetMember for Semaphore {
+    fn member_handle(&self) -> QueueSetMemberHandle_t {
+        self.raw()
+    }
+}
+
+unsafe impl QueueSetMember for BinarySemaphore {
+    fn member_handle(&self) -> QueueSetMemberHandle_t {
+        self.raw()
+    }
+}
+
+unsafe impl QueueSetMember for CountingSemaphore {
+    fn member_handle(&self) -> QueueSetMemberHandle_t {
+        self.raw()
+    }
+}
+
+/// A set of up to `N` queues and semaphores, each identified by a key of type `K`.
+///
+/// [`QueueSet::select`] blocks until any member contains data and returns its key,
+/// usually a user defined enum. The data must then be read from that member (without
+/// blocking) before calling `select` again.
+///
+/// The kernel only removes members that are empty, so all members must be drained
+/// before the set is dropped. [`QueueSet::close`] returns the set if a member still
+/// contains data, while dropping such a set panics: that member keeps a pointer to the
+/// set, so it is leaked, and sending to the member keeps posting to the set until the
+/// kernel asserts that it is full.
+///
+/// ```ignore
+/// #[derive(Clone, Copy)]
+/// enum Event {
+///     Uart,
+///     Button,
+/// }
+///
+/// let mut set = QueueSet::<Event, 2>::new(UART_QUEUE_LEN + 1)?;
+/// set.add(&uart_queue, Event::Uart)?;
+/// set.add(&button_semaphore, Event::Button)?;
+///
+/// loop {
+///     match set.select(Ticks::MAX)? {
+///         Event::Uart => handle_byte(uart_queue.receive(Ticks::ZERO)?),
+///         Event::Button => button_semaphore.take(Ticks::ZERO)?,
+///     }
+/// }
+/// ```
+pub struct QueueSet<'a, K: Copy, const N: usize> {
+    handle: QueueSetHandle_t,
+    members: [Option<(QueueSetMemberHandle_t, K)>; N],
+    _members: PhantomData<&'a ()>,
+}
+
+unsafe impl<K: Copy + Send, const N: usize> Send for QueueSet<'_, K, N> {}
+unsafe impl<K: Copy + Sync, const N: usize> Sync for QueueSet<'_, K, N> {}
+
+impl<'a, K: Copy, const N: usize> QueueSet<'a, K, N> {
+    /// Create a new queue set.
+    ///
+    /// `length` must be at least the sum of the lengths of all queues and the maximum
+    /// counts of all semaphores that will be added to the set.
+    pub fn new(length: usize) -> Result<QueueSet<'a, K, N>, Error> {
+        let handle = unsafe { xQueueCreateSet(length as UBaseType_t) };
+        if handle.is_null() {
+            return Err(Error::OutOfMemory);
+        }
+
+        Ok(QueueSet {
+            handle,
+            members: [None; N],
+            _members: PhantomData,
+        })
+    }
+
+    /// Get the raw FreeRTOS queue set handle.
+    pub fn raw(&self) -> QueueSetHandle_t {
+        self.handle
+    }
+
+    /// Add `member` to the set, identified by `key`.
+    ///
+    /// Fails with [`Error::QueueFull`] if the set already has `N` members and with
+    /// [`Error::QueueSetMember`] if `member` isn't empty or already part of a set.
+    pub fn add<M: QueueSetMember>(&mut self, member: &'a M, key: K) -> Result<(), Error> {
+        let slot = self
+            .members
+            .iter_mut()
+            .find(|m| m.is_none())
+            .ok_or(Error::QueueFull)?;
+
+        let handle = member.member_handle();
+        if unsafe { xQueueAddToSet(handle, self.handle) } != pdPASS {
+            return Err(Error::QueueSetMember);
+        }
+        *slot = Some((handle, key));
+
+        Ok(())
+    }
+
+    /// Remove `member` from the set.
+    ///
+    /// Fails with [`Error::QueueSetMember`] if `member` isn't part of the set or still
+    /// contains data.
+    pub fn remove<M: QueueSetMember>(&mut self, member: &M) -> Result<(), Error> {
+        let handle = member.member_handle();
+        let slot = self
+            .members
+            .iter_mut()
+            .find(|m| matches!(m, Some((h, _)) if *h == handle))
+            .ok_or(Error::QueueSetMember)?;
+
+        if unsafe { xQueueRemoveFromSet(handle, self.handle) } != pdPASS {
+            return Err(Error::QueueSetMember);
+        }
+        *slot = None;
+
+        Ok(())
+    }
+
+    /// Wait up to `timeout` for any member to contain data, returning its key.
+    pub fn select(&self, timeout: Ticks) -> Result<K, Error> {
+        let handle = unsafe { xQueueSelectFromSet(self.handle, timeout.raw()) };
+        self.key_of(handle).ok_or(Error::Timeout)
+    }
+
+    /// Get the key of a member that contains data from an ISR.
+    pub fn select_from_isr(&self) -> Result<K, Error> {
+        let handle = unsafe { xQueueSelectFromSetFromISR(self.handle) };
+        self.key_of(handle).ok_or(Error::QueueEmpty)
+    }
+
+    /// Remove all members and delete the set.
+    ///
+    /// Fails with [`Error::QueueSetMember`] if a member still contains data. The set is
+    /// returned with only the members that contain data, so they can be drained and the
+    /// set closed again.
+    pub fn close(mut self) -> Result<(), (QueueSet<'a, K, N>, Error)> {
+        match self.remove_all() {
+            Ok(()) => Ok(()),
+            Err(err) => Err((self, err)),
+        }
+    }
+
+    /// Remove all empty members, failing if any member is left.
+    fn remove_all(&mut self) -> Result<(), Error> {
+        let mut res = Ok(());
+        for slot in self.members.iter_mut() {
+            if let Some((member, _)) = *slot {
+                if unsafe { xQueueRemoveFromSet(member, self.handle) } == pdPASS {
+                    *slot = None;
+                } else {
+                    res = Err(Error::QueueSetMember);
+                }
+            }
+        }
+
+        res
+    }
+
+    fn key_of(&self, handle: QueueSetMemberHandle_t) -> Option<K> {
+        if handle.is_null() {
+            return None;
+        }
+
+        self.members
+            .iter()
+            .flatten()
+            .find(|(h, _)| *h == handle)
+            .map(|(_, key)| *key)
+    }
+}
+
+impl<K: Copy, const N: usize> Drop for QueueSet<'_, K, N> {
+    fn drop(&mut self) {
+        // Members keep a pointer to their set, so they have to be removed before the set
+        // is deleted. The kernel only allows removing empty members, the data of the
+        // others belongs to the caller, so the set is leaked instead.
+        let removed = self.remove_all().is_ok();
+        if removed {
+            unsafe { vQueueDelete(self.handle) }
+        }
+        assert!(removed, "queue set dropped while a member contains data");
+    }
+}
+
+#[cfg(all(test, feature = "host-sim", feature = "use-rust-alloc"))]
+mod tests {
+    use super::QueueSet;
+    use crate::error::Error;
+    use crate::queue::Queue;
+    use crate::semaphore::BinarySemaphore;
+    use crate::sim;
+    use crate::ticks::Ticks;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Key {
+        Queue,
+        Semaphore,
+    }
+
+    #[test]
+    fn select_returns_the_member_with_data() {
+        sim::run(|| {
+            let queue = Queue::<u32>::new(2).unwrap();
+            let semaphore = BinarySemaphore::new().unwrap();
+            let mut set = QueueSet::<Key, 2>::new(3).unwrap();
+            set.add(&queue, Key::Queue).unwrap();
+            set.add(&semaphore, Key::Semaphore).unwrap();
+            assert_eq!(set.add(&queue, Key::Queue), Err(Error::QueueFull));
+            assert_eq!(set.select(Ticks::ZERO), Err(Error::Timeout));
+
+            semaphore.give().unwrap();
+            queue.send(7, Ticks::ZERO).unwrap();
+            assert_eq!(set.select(Ticks::ZERO), Ok(Key::Semaphore));
+            semaphore.take(Ticks::ZERO).unwrap();
+            assert_eq!(set.select(Ticks::ZERO), Ok(Key::Queue));
+            assert_eq!(queue.receive(Ticks::ZERO), Ok(7));
+            assert_eq!(set.select(Ticks::ZERO), Err(Error::Timeout));
+
+            set.close().map_err(|(_, err)| err).unwrap();
+        });
+    }
+
+    #[test]
+    fn only_empty_members_are_removed() {
+        sim::run(|| {
+            let queue = Queue::<u32>::new(1).unwrap();
+            let other = Queue::<u32>::new(1).unwrap();
+            let mut set = QueueSet::<Key, 2>::new(1).unwrap();
+            set.add(&queue, Key::Queue).unwrap();
+            assert_eq!(set.remove(&other), Err(Error::QueueSetMember));
+
+            queue.send(1, Ticks::ZERO).unwrap();
+            assert_eq!(set.remove(&queue), Err(Error::QueueSetMember));
+            let (mut set, err) = set.close().unwrap_err();
+            assert_eq!(err, Error::QueueSetMember);
+
+            assert_eq!(set.select(Ticks::ZERO), Ok(Key::Queue));
+            assert_eq!(queue.receive(Ticks::ZERO), Ok(1));
+            set.remove(&queue).unwrap();
+            assert_eq!(set.select(Ticks::ZERO), Err(Error::Timeout));
+
+            // The removed queue can join another set.
+            let mut other_set = QueueSet::<Key, 1>::new(1).unwrap();
+            other_set.add(&queue, Key::Queue).unwrap();
+        });
+    }
+}
@@ -0,0 +1,323 @@
+//! Binary and counting semaphores.
+//!
+//! Unlike mutexes, semaphores have no owner and don't implement priority inheritance,
+//! which makes them suitable for signalling from ISRs to tasks.
+
+use core::ops::Deref;
+use core::ptr;
+
+use crate::error::Error;
+use crate::ticks::Ticks;
+use crate::{
+    pdFALSE, pdTRUE, queueQUEUE_TYPE_BINARY_SEMAPHORE, queueSEND_TO_BACK, uxQueueMessagesWaiting,
+    uxQueueMessagesWaitingFromISR, vQueueDelete, xQueueCreateCountingSemaphore,
+    xQueueGenericCreate, xQueueGenericSend, xQueueGiveFromISR, xQueueReceiveFromISR,
+    xQueueSemaphoreTake, BaseType_t, QueueHandle_t, UBaseType_t,
+};
+
+#[cfg(feature = "static-alloc")]
+pub use static_impl::*;
+
+/// The operations shared by [`BinarySemaphore`] and [`CountingSemaphore`].
+///
+/// The `*_from_isr` variants never block and additionally return whether a higher
+/// priority task was woken, in which case the ISR should yield (see
+/// [`vPortYieldFromISR`]) before it returns.
+///
+/// [`vPortYieldFromISR`]: crate::vPortYieldFromISR
+pub struct Semaphore {
+    handle: QueueHandle_t,
+}
+
+unsafe impl Send for Semaphore {}
+unsafe impl Sync for Semaphore {}
+
+impl Semaphore {
+    fn from_handle(handle: QueueHandle_t) -> Result<Semaphore, Error> {
+        if handle.is_null() {
+            Err(Error::OutOfMemory)
+        } else {
+            Ok(Semaphore { handle })
+        }
+    }
+
+    /// Get the raw FreeRTOS semaphore handle.
+    pub fn raw(&self) -> QueueHandle_t {
+        self.handle
+    }
+
+    /// Take the semaphore, waiting up to `timeout` for it to become available.
+    pub fn take(&self, timeout: Ticks) -> Result<(), Error> {
+        if unsafe { xQueueSemaphoreTake(self.handle, timeout.raw()) } == pdTRUE {
+            Ok(())
+        } else {
+            Err(Error::Timeout)
+        }
+    }
+
+    /// Give the semaphore.
+    ///
+    /// Fails with [`Error::QueueFull`] if the semaphore is already at its maximum count.
+    pub fn give(&self) -> Result<(), Error> {
+        let res = unsafe { xQueueGenericSend(self.handle, ptr::null(), 0, queueSEND_TO_BACK) };
+        if res == pdTRUE {
+            Ok(())
+        } else {
+            Err(Error::QueueFull)
+        }
+    }
+
+    /// Take the semaphore from an ISR.
+    ///
+    /// Returns whether a higher priority task was woken.
+    pub fn take_from_isr(&self) -> Result<bool, Error> {
+        let mut woken: BaseType_t = pdFALSE;
+        let res =
+            unsafe { xQueueReceiveFromISR(self.handle, ptr::null_mut(), &mut woken as *mut _) };
+        if res == pdTRUE {
+            Ok(woken != pdFALSE)
+        } else {
+            Err(Error::QueueEmpty)
+        }
+    }
+
+    /// Give the semaphore from an ISR.
+    ///
+    /// Returns whether a higher priority task was woken.
+    pub fn give_from_isr(&self) -> Result<bool, Error> {
+        let mut woken: BaseType_t = pdFALSE;
+        let res = unsafe { xQueueGiveFromISR(self.handle, &mut woken as *mut _) };
+        if res == pdTRUE {
+            Ok(woken != pdFALSE)
+        } else {
+            Err(Error::QueueFull)
+        }
+    }
+
+    /// The current count of the semaphore (`1` or `0` for binary semaphores).
+    pub fn count(&self) -> usize {
+        unsafe { uxQueueMessagesWaiting(self.handle) as usize }
+    }
+
+    /// The current count of the semaphore, callable from an ISR.
+    pub fn count_from_isr(&self) -> usize {
+        unsafe { uxQueueMessagesWaitingFromISR(self.handle) as usize }
+    }
+}
+
+impl Drop for Semaphore {
+    fn drop(&mut self) {
+        unsafe { vQueueDelete(self.handle) }
+    }
+}
+
+/// A semaphore that is either available or not.
+///
+/// The semaphore is created empty, so it must be given before it can be taken.
+pub struct BinarySemaphore(Semaphore);
+
+impl BinarySemaphore {
+    /// Create a new binary semaphore.
+    pub fn new() -> Result<BinarySemaphore, Error> {
+        let handle = unsafe { xQueueGenericCreate(1, 0, queueQUEUE_TYPE_BINARY_SEMAPHORE) };
+        Semaphore::from_handle(handle).map(BinarySemaphore)
+    }
+}
+
+impl Deref for BinarySemaphore {
+    type Target = Semaphore;
+
+    fn deref(&self) -> &Semaphore {
+        &self.0
+    }
+}
+
+/// A semaphore that counts up to a maximum value.
+pub struct CountingSemaphore(Semaphore);
+
+impl CountingSemaphore {
+    /// Create a new counting semaphore with a maximum count of `max` and an initial
+    /// count of `initial`.
+    ///
+    /// Fails with [`Error::InvalidArgument`] if `max` is zero or `initial` is larger
+    /// than `max`, which the kernel rejects.
+    pub fn new(max: usize, initial: usize) -> Result<CountingSemaphore, Error> {
+        if max == 0 || initial > max {
+            return Err(Error::InvalidArgument);
+        }
+
+        let handle =
+            unsafe { xQueueCreateCountingSemaphore(max as UBaseType_t, initial as UBaseType_t) };
+        Semaphore::from_handle(handle).map(CountingSemaphore)
+    }
+}
+
+impl Deref for CountingSemaphore {
+    type Target = Semaphore;
+
+    fn deref(&self) -> &Semaphore {
+        &self.0
+    }
+}
+
+#[cfg(feature = "static-alloc")]
+mod static_impl {
+    use core::cell::UnsafeCell;
+    use core::mem::MaybeUninit;
+    use core::ptr;
+
+    use super::{BinarySemaphore, CountingSemaphore, Semaphore};
+    use crate::atomic::Once;
+    use crate::{
+        queueQUEUE_TYPE_BINARY_SEMAPHORE, xQueueCreateCountingSemaphoreStatic,
+        xQueueGenericCreateStatic, StaticSemaphore_t, UBaseType_t,
+    };
+
+    /// A [`BinarySemaphore`] whose memory is part of the `static` item it is placed in.
+    ///
+    /// The semaphore is created on the first call to [`StaticBinarySemaphore::get`].
+    pub struct StaticBinarySemaphore {
+        buffer: UnsafeCell<MaybeUninit<StaticSemaphore_t>>,
+        semaphore: Once<BinarySemaphore>,
+    }
+
+    unsafe impl Sync for StaticBinarySemaphore {}
+
+    impl Default for StaticBinarySemaphore {
+        fn default() -> Self {
+            StaticBinarySemaphore::new()
+        }
+    }
+
+    impl StaticBinarySemaphore {
+        /// Create the uninitialized semaphore memory.
+        pub const fn new() -> StaticBinarySemaphore {
+            StaticBinarySemaphore {
+                buffer: UnsafeCell::new(MaybeUninit::uninit()),
+                semaphore: Once::new(),
+            }
+        }
+
+        /// Get the semaphore, creating it on the first call.
+        pub fn get(&'static self) -> &'static BinarySemaphore {
+            self.semaphore.call_once(|| {
+                // Can't fail, as all memory is provided.
+                BinarySemaphore(Semaphore {
+                    handle: unsafe {
+                        xQueueGenericCreateStatic(
+                            1,
+                            0,
+                            ptr::null_mut(),
+                            self.buffer.get() as *mut StaticSemaphore_t,
+                            queueQUEUE_TYPE_BINARY_SEMAPHORE,
+                        )
+                    },
+                })
+            })
+        }
+    }
+
+    /// A [`CountingSemaphore`] whose memory is part of the `static` item it is placed in.
+    ///
+    /// The semaphore is created on the first call to [`StaticCountingSemaphore::get`].
+    pub struct StaticCountingSemaphore {
+        max: usize,
+        initial: usize,
+        buffer: UnsafeCell<MaybeUninit<StaticSemaphore_t>>,
+        semaphore: Once<CountingSemaphore>,
+    }
+
+    unsafe impl Sync for StaticCountingSemaphore {}
+
+    impl StaticCountingSemaphore {
+        /// Create the uninitialized memory of a semaphore with a maximum count of `max`
+        /// and an initial count of `initial`.
+        ///
+        /// Panics (at compile time in a `static` item) if `max` is zero or `initial` is
+        /// larger than `max`, which the kernel rejects.
+        pub const fn new(max: usize, initial: usize) -> StaticCountingSemaphore {
+            assert!(max > 0, "the maximum count must not be zero");
+            assert!(
+                initial <= max,
+                "the initial count must not exceed the maximum"
+            );
+            StaticCountingSemaphore {
+                max,
+                initial,
+                buffer: UnsafeCell::new(MaybeUninit::uninit()),
+                semaphore: Once::new(),
+            }
+        }
+
+        /// Get the semaphore, creating it on the first call.
+        pub fn get(&'static self) -> &'static CountingSemaphore {
+            self.semaphore.call_once(|| {
+                // Can't fail, as all memory is provided and `new` checked the counts.
+                CountingSemaphore(Semaphore {
+                    handle: unsafe {
+                        xQueueCreateCountingSemaphoreStatic(
+                            self.max as UBaseType_t,
+                            self.initial as UBaseType_t,
+                            self.buffer.get() as *mut StaticSemaphore_t,
+                        )
+                    },
+                })
+            })
+        }
+    }
+}
+
+#[cfg(all(test, feature = "host-sim", feature = "use-rust-alloc"))]
+mod tests {
+    extern crate std;
+    use std::sync::Arc;
+
+    use super::{BinarySemaphore, CountingSemaphore};
+    use crate::error::Error;
+    use crate::sim;
+    use crate::task::spawn;
+    use crate::ticks::Ticks;
+
+    #[test]
+    fn binary_semaphore() {
+        sim::run(|| {
+            let semaphore = BinarySemaphore::new().unwrap();
+            assert_eq!(semaphore.take(Ticks::ZERO), Err(Error::Timeout));
+
+            semaphore.give().unwrap();
+            assert_eq!(semaphore.count(), 1);
+            assert_eq!(semaphore.give(), Err(Error::QueueFull));
+            semaphore.take(Ticks::ZERO).unwrap();
+            assert_eq!(semaphore.count(), 0);
+        });
+    }
+
+    #[test]
+    fn counting_semaphore_wakes_a_waiting_task() {
+        sim::run(|| {
+            let semaphore = Arc::new(CountingSemaphore::new(3, 1).unwrap());
+            assert_eq!(semaphore.count(), 1);
+
+            let giver = semaphore.clone();
+            let handle = spawn(move || {
+                giver.give().unwrap();
+                giver.give().unwrap();
+            })
+            .unwrap();
+            for _ in 0..3 {
+                semaphore.take(Ticks::MAX).unwrap();
+            }
+            handle.join();
+            assert_eq!(semaphore.take(Ticks::new(1)), Err(Error::Timeout));
+        });
+    }
+    #[test]
+    fn invalid_counts_are_rejected() {
+        sim::run(|| {
+            let invalid = Some(Error::InvalidArgument);
+            assert_eq!(CountingSemaphore::new(0, 0).err(), invalid);
+            assert_eq!(CountingSemaphore::new(2, 3).err(), invalid);
+            assert_eq!(CountingSemaphore::new(2, 2).unwrap().count(), 2);
+        });
+    }
+}
@@ -0,0 +1,80 @@
+//! Runs the tests of the `host-sim` feature on the simulated kernel.
+//!
+//! The kernel may only be called from the threads of its own tasks, so the scheduler is
+//! started once on a dedicated thread and [`run`] hands every test body to a task, while
+//! the test thread waits for its result.
+
+extern crate std;
+
+use core::ptr;
+
+use chlorine::{c_char, c_void};
+use std::boxed::Box;
+use std::collections::VecDeque;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Mutex, Once};
+use std::thread;
+
+use crate::task::DEFAULT_PRIORITY;
+use crate::{pdPASS, vTaskDelay, vTaskDelete, vTaskStartScheduler, xTaskCreate};
+
+/// The stack depth of the test tasks, enough for the formatting of failed asserts.
+const STACK_DEPTH: u16 = 16 * 1024;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+static SCHEDULER: Once = Once::new();
+/// The test bodies waiting for their task.
+static JOBS: Mutex<VecDeque<Job>> = Mutex::new(VecDeque::new());
+
+/// Run `test` in a new task and wait for it to finish, panicking if it panicked.
+pub fn run<F>(test: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    SCHEDULER.call_once(|| {
+        thread::spawn(|| {
+            // Polls for test bodies, a foreign thread must not give a kernel semaphore.
+            spawn(Box::new(|| loop {
+                let job = JOBS.lock().unwrap().pop_front();
+                match job {
+                    Some(job) => spawn(job),
+                    None => unsafe { vTaskDelay(1) },
+                }
+            }));
+            unsafe { vTaskStartScheduler() };
+        });
+    });
+
+    let (sender, receiver) = mpsc::channel();
+    JOBS.lock().unwrap().push_back(Box::new(move || {
+        let _ = sender.send(panic::catch_unwind(AssertUnwindSafe(test)));
+    }));
+    let result = receiver
+        .recv()
+        .expect("the test task exited without a result");
+    if let Err(payload) = result {
+        panic::resume_unwind(payload);
+    }
+}
+
+/// Create a task running `job`.
+fn spawn(job: Job) {
+    let job = Box::into_raw(Box::new(job));
+    let res = unsafe {
+        xTaskCreate(
+            Some(job_entry),
+            b"test\0".as_ptr() as *const c_char,
+            STACK_DEPTH,
+            job as *mut c_void,
+            DEFAULT_PRIORITY,
+            ptr::null_mut(),
+        )
+    };
+    assert_eq!(res, pdPASS, "failed to create a test task");
+}
+
+unsafe extern "C" fn job_entry(job: *mut c_void) {
+    Box::from_raw(job as *mut Job)();
+    vTaskDelete(ptr::null_mut());
+}
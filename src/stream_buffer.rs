@@ -0,0 +1,647 @@
+//! Stream buffers and message buffers.
+//!
+//! Both are optimized for a single writer and a single reader, e.g. an ISR and a task.
+//! This is enforced by splitting a buffer into a producer and a consumer half that are
+//! `Send` but not `Sync`. The `*_from_isr` variants never block and additionally
+//! return whether a higher priority task was woken, in which case the ISR should yield
+//! (see [`vPortYieldFromISR`]) before it returns.
+//!
+//! [`vPortYieldFromISR`]: crate::vPortYieldFromISR
+
+use core::cell::Cell;
+use core::marker::PhantomData;
+use core::mem;
+
+use chlorine::c_void;
+
+use crate::error::Error;
+use crate::ticks::Ticks;
+use crate::{
+    pdFALSE, pdPASS, pdTRUE, vStreamBufferDelete, xStreamBufferBytesAvailable,
+    xStreamBufferGenericCreate, xStreamBufferIsEmpty, xStreamBufferIsFull,
+    xStreamBufferNextMessageLengthBytes, xStreamBufferReceive, xStreamBufferReceiveFromISR,
+    xStreamBufferReset, xStreamBufferSend, xStreamBufferSendFromISR, xStreamBufferSetTriggerLevel,
+    xStreamBufferSpacesAvailable, BaseType_t, StreamBufferHandle_t,
+};
+
+#[cfg(feature = "static-alloc")]
+pub use static_impl::*;
+
+/// Marker that makes the buffer halves `Send` but not `Sync`.
+type NotSync = PhantomData<Cell<()>>;
+
+/// An owned stream or message buffer handle.
+struct Handle(StreamBufferHandle_t);
+
+unsafe impl Send for Handle {}
+unsafe impl Sync for Handle {}
+
+impl Handle {
+    fn new(size: usize, trigger_level: usize, is_message_buffer: bool) -> Result<Handle, Error> {
+        // The kernel only asserts these.
+        let valid = if is_message_buffer {
+            size > mem::size_of::<usize>()
+        } else {
+            trigger_level > 0 && trigger_level <= size
+        };
+        if !valid {
+            return Err(Error::InvalidArgument);
+        }
+
+        let handle = unsafe {
+            xStreamBufferGenericCreate(
+                size,
+                trigger_level,
+                if is_message_buffer { pdTRUE } else { pdFALSE },
+            )
+        };
+        if handle.is_null() {
+            Err(Error::OutOfMemory)
+        } else {
+            Ok(Handle(handle))
+        }
+    }
+
+    fn send(&self, data: &[u8], timeout: Ticks) -> usize {
+        unsafe {
+            xStreamBufferSend(
+                self.0,
+                data.as_ptr() as *const c_void,
+                data.len(),
+                timeout.raw(),
+            )
+        }
+    }
+
+    fn send_from_isr(&self, data: &[u8]) -> (usize, bool) {
+        let mut woken: BaseType_t = pdFALSE;
+        let sent = unsafe {
+            xStreamBufferSendFromISR(
+                self.0,
+                data.as_ptr() as *const c_void,
+                data.len(),
+                &mut woken as *mut _,
+            )
+        };
+        (sent, woken != pdFALSE)
+    }
+
+    fn receive(&self, buf: &mut [u8], timeout: Ticks) -> usize {
+        unsafe {
+            xStreamBufferReceive(
+                self.0,
+                buf.as_mut_ptr() as *mut c_void,
+                buf.len(),
+                timeout.raw(),
+            )
+        }
+    }
+
+    fn receive_from_isr(&self, buf: &mut [u8]) -> (usize, bool) {
+        let mut woken: BaseType_t = pdFALSE;
+        let received = unsafe {
+            xStreamBufferReceiveFromISR(
+                self.0,
+                buf.as_mut_ptr() as *mut c_void,
+                buf.len(),
+                &mut woken as *mut _,
+            )
+        };
+        (received, woken != pdFALSE)
+    }
+
+    fn bytes_available(&self) -> usize {
+        unsafe { xStreamBufferBytesAvailable(self.0) }
+    }
+
+    fn spaces_available(&self) -> usize {
+        unsafe { xStreamBufferSpacesAvailable(self.0) }
+    }
+
+    fn is_empty(&self) -> bool {
+        unsafe { xStreamBufferIsEmpty(self.0) == pdTRUE }
+    }
+
+    fn is_full(&self) -> bool {
+        unsafe { xStreamBufferIsFull(self.0) == pdTRUE }
+    }
+
+    fn reset(&self) -> bool {
+        unsafe { xStreamBufferReset(self.0) == pdPASS }
+    }
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        unsafe { vStreamBufferDelete(self.0) }
+    }
+}
+
+/// A byte pipe that wakes the reader once `trigger_level` bytes are available.
+pub struct StreamBuffer {
+    handle: Handle,
+}
+
+impl StreamBuffer {
+    /// Create a stream buffer that can hold `size` bytes.
+    ///
+    /// A reader blocked on an empty buffer is woken once `trigger_level` bytes were
+    /// written (or its timeout expires). Fails with [`Error::InvalidArgument`] if
+    /// `trigger_level` is zero or larger than `size`.
+    pub fn new(size: usize, trigger_level: usize) -> Result<StreamBuffer, Error> {
+        Ok(StreamBuffer {
+            handle: Handle::new(size, trigger_level, false)?,
+        })
+    }
+
+    /// Get the raw FreeRTOS stream buffer handle.
+    pub fn raw(&self) -> StreamBufferHandle_t {
+        self.handle.0
+    }
+
+    /// Split the buffer into its producer and consumer half.
+    pub fn split(&mut self) -> (StreamProducer<'_>, StreamConsumer<'_>) {
+        self.halves()
+    }
+
+    fn halves(&self) -> (StreamProducer<'_>, StreamConsumer<'_>) {
+        (
+            StreamProducer {
+                handle: &self.handle,
+                _not_sync: PhantomData,
+            },
+            StreamConsumer {
+                handle: &self.handle,
+                _not_sync: PhantomData,
+            },
+        )
+    }
+
+    /// Set the number of bytes that must be available before a blocked reader is
+    /// woken.
+    ///
+    /// Fails with [`Error::QueueFull`] if `trigger_level` is larger than the buffer.
+    pub fn set_trigger_level(&self, trigger_level: usize) -> Result<(), Error> {
+        if unsafe { xStreamBufferSetTriggerLevel(self.handle.0, trigger_level) } == pdTRUE {
+            Ok(())
+        } else {
+            Err(Error::QueueFull)
+        }
+    }
+
+    /// The number of bytes that can be read.
+    pub fn bytes_available(&self) -> usize {
+        self.handle.bytes_available()
+    }
+
+    /// The number of bytes that can be written.
+    pub fn spaces_available(&self) -> usize {
+        self.handle.spaces_available()
+    }
+
+    /// Whether the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.handle.is_empty()
+    }
+
+    /// Whether the buffer is full.
+    pub fn is_full(&self) -> bool {
+        self.handle.is_full()
+    }
+
+    /// Discard all bytes in the buffer.
+    ///
+    /// Returns `false` if a task is blocked on the buffer, in which case it isn't reset.
+    pub fn reset(&mut self) -> bool {
+        self.handle.reset()
+    }
+}
+
+/// The writing half of a [`StreamBuffer`].
+pub struct StreamProducer<'a> {
+    handle: &'a Handle,
+    _not_sync: NotSync,
+}
+
+impl StreamProducer<'_> {
+    /// Write as many bytes of `data` as fit, waiting up to `timeout` for enough space.
+    ///
+    /// Returns the number of bytes written.
+    pub fn send(&self, data: &[u8], timeout: Ticks) -> usize {
+        self.handle.send(data, timeout)
+    }
+
+    /// Write as many bytes of `data` as fit from an ISR.
+    ///
+    /// Returns the number of bytes written and whether a higher priority task was
+    /// woken.
+    pub fn send_from_isr(&self, data: &[u8]) -> (usize, bool) {
+        self.handle.send_from_isr(data)
+    }
+
+    /// The number of bytes that can be written.
+    pub fn spaces_available(&self) -> usize {
+        self.handle.spaces_available()
+    }
+}
+
+/// The reading half of a [`StreamBuffer`].
+pub struct StreamConsumer<'a> {
+    handle: &'a Handle,
+    _not_sync: NotSync,
+}
+
+impl StreamConsumer<'_> {
+    /// Read up to `buf.len()` bytes, waiting up to `timeout` for the trigger level to
+    /// be reached.
+    ///
+    /// Returns the number of bytes read.
+    pub fn receive(&self, buf: &mut [u8], timeout: Ticks) -> usize {
+        self.handle.receive(buf, timeout)
+    }
+
+    /// Read up to `buf.len()` bytes from an ISR.
+    ///
+    /// Returns the number of bytes read and whether a higher priority task was woken.
+    pub fn receive_from_isr(&self, buf: &mut [u8]) -> (usize, bool) {
+        self.handle.receive_from_isr(buf)
+    }
+
+    /// The number of bytes that can be read.
+    pub fn bytes_available(&self) -> usize {
+        self.handle.bytes_available()
+    }
+}
+
+/// A buffer of variable length messages.
+///
+/// Every message is stored with a `usize` length prefix, which counts towards the size
+/// of the buffer.
+pub struct MessageBuffer {
+    handle: Handle,
+}
+
+impl MessageBuffer {
+    /// Create a message buffer that can hold `size` bytes, including the length
+    /// prefixes.
+    ///
+    /// Fails with [`Error::InvalidArgument`] if `size` doesn't leave room for a message
+    /// after the `usize` length prefix.
+    pub fn new(size: usize) -> Result<MessageBuffer, Error> {
+        Ok(MessageBuffer {
+            // The trigger level is ignored for message buffers.
+            handle: Handle::new(size, 0, true)?,
+        })
+    }
+
+    /// Get the raw FreeRTOS message buffer handle.
+    pub fn raw(&self) -> StreamBufferHandle_t {
+        self.handle.0
+    }
+
+    /// Split the buffer into its producer and consumer half.
+    pub fn split(&mut self) -> (MessageProducer<'_>, MessageConsumer<'_>) {
+        self.halves()
+    }
+
+    fn halves(&self) -> (MessageProducer<'_>, MessageConsumer<'_>) {
+        (
+            MessageProducer {
+                handle: &self.handle,
+                _not_sync: PhantomData,
+            },
+            MessageConsumer {
+                handle: &self.handle,
+                _not_sync: PhantomData,
+            },
+        )
+    }
+
+    /// The number of bytes that can be written, including the length prefix.
+    pub fn spaces_available(&self) -> usize {
+        self.handle.spaces_available()
+    }
+
+    /// Whether the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.handle.is_empty()
+    }
+
+    /// Whether the buffer is full.
+    pub fn is_full(&self) -> bool {
+        self.handle.is_full()
+    }
+
+    /// Discard all messages in the buffer.
+    ///
+    /// Returns `false` if a task is blocked on the buffer, in which case it isn't reset.
+    pub fn reset(&mut self) -> bool {
+        self.handle.reset()
+    }
+}
+
+/// The writing half of a [`MessageBuffer`].
+pub struct MessageProducer<'a> {
+    handle: &'a Handle,
+    _not_sync: NotSync,
+}
+
+impl MessageProducer<'_> {
+    /// Write `message`, waiting up to `timeout` for enough space.
+    ///
+    /// Fails with [`Error::InvalidArgument`] if `message` is empty, as the reader
+    /// couldn't tell it from no message at all.
+    pub fn send(&self, message: &[u8], timeout: Ticks) -> Result<(), Error> {
+        if message.is_empty() {
+            return Err(Error::InvalidArgument);
+        }
+
+        if self.handle.send(message, timeout) == message.len() {
+            Ok(())
+        } else {
+            Err(Error::QueueFull)
+        }
+    }
+
+    /// Write `message` from an ISR.
+    ///
+    /// Returns whether a higher priority task was woken. Fails with
+    /// [`Error::InvalidArgument`] if `message` is empty.
+    pub fn send_from_isr(&self, message: &[u8]) -> Result<bool, Error> {
+        if message.is_empty() {
+            return Err(Error::InvalidArgument);
+        }
+
+        match self.handle.send_from_isr(message) {
+            (sent, woken) if sent == message.len() => Ok(woken),
+            _ => Err(Error::QueueFull),
+        }
+    }
+
+    /// The number of bytes that can be written, including the length prefix.
+    pub fn spaces_available(&self) -> usize {
+        self.handle.spaces_available()
+    }
+}
+
+/// The reading half of a [`MessageBuffer`].
+pub struct MessageConsumer<'a> {
+    handle: &'a Handle,
+    _not_sync: NotSync,
+}
+
+impl MessageConsumer<'_> {
+    /// Read the next message into `buf`, waiting up to `timeout` for one.
+    ///
+    /// Returns the length of the message. Fails with [`Error::BufferTooSmall`] if the
+    /// message doesn't fit into `buf`, in which case it stays in the buffer.
+    pub fn receive(&self, buf: &mut [u8], timeout: Ticks) -> Result<usize, Error> {
+        match self.handle.receive(buf, timeout) {
+            0 => Err(self.empty_error(Error::Timeout)),
+            len => Ok(len),
+        }
+    }
+
+    /// Read the next message into `buf` from an ISR.
+    ///
+    /// Returns the length of the message and whether a higher priority task was woken.
+    pub fn receive_from_isr(&self, buf: &mut [u8]) -> Result<(usize, bool), Error> {
+        match self.handle.receive_from_isr(buf) {
+            (0, _) => Err(self.empty_error(Error::QueueEmpty)),
+            res => Ok(res),
+        }
+    }
+
+    /// The length of the next message, or zero if the buffer is empty.
+    pub fn next_message_len(&self) -> usize {
+        unsafe { xStreamBufferNextMessageLengthBytes(self.handle.0) }
+    }
+
+    fn empty_error(&self, error: Error) -> Error {
+        if self.next_message_len() == 0 {
+            error
+        } else {
+            Error::BufferTooSmall
+        }
+    }
+}
+
+#[cfg(feature = "static-alloc")]
+mod static_impl {
+    use core::cell::UnsafeCell;
+    use core::mem::{self, MaybeUninit};
+    use core::sync::atomic::AtomicBool;
+
+    use super::{
+        Handle, MessageBuffer, MessageConsumer, MessageProducer, StreamBuffer, StreamConsumer,
+        StreamProducer,
+    };
+    use crate::atomic::{self, Once};
+    use crate::error::Error;
+    use crate::{pdFALSE, pdTRUE, xStreamBufferGenericCreateStatic, StaticStreamBuffer_t};
+
+    /// The memory of a stream or message buffer with `N` bytes of storage.
+    struct StaticHandle<const N: usize> {
+        storage: UnsafeCell<MaybeUninit<[u8; N]>>,
+        buffer: UnsafeCell<MaybeUninit<StaticStreamBuffer_t>>,
+        split: AtomicBool,
+    }
+
+    impl<const N: usize> StaticHandle<N> {
+        const fn new() -> StaticHandle<N> {
+            StaticHandle {
+                storage: UnsafeCell::new(MaybeUninit::uninit()),
+                buffer: UnsafeCell::new(MaybeUninit::uninit()),
+                split: AtomicBool::new(false),
+            }
+        }
+
+        fn create(&'static self, trigger_level: usize, is_message_buffer: bool) -> Handle {
+            // The kernel keeps one byte of the storage free to tell a full buffer from an
+            // empty one. Can't fail, as all memory is provided and the constructors
+            // checked the sizes.
+            Handle(unsafe {
+                xStreamBufferGenericCreateStatic(
+                    N,
+                    trigger_level,
+                    if is_message_buffer { pdTRUE } else { pdFALSE },
+                    self.storage.get() as *mut u8,
+                    self.buffer.get() as *mut StaticStreamBuffer_t,
+                )
+            })
+        }
+
+        fn take_split(&self) -> Result<(), Error> {
+            if atomic::swap(&self.split, true) {
+                Err(Error::InUse)
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    /// A [`StreamBuffer`] with `N` bytes of storage that is part of the `static` item it
+    /// is placed in, holding up to `N - 1` bytes.
+    ///
+    /// The buffer is created on the first call to [`StaticStreamBuffer::get`] or
+    /// [`StaticStreamBuffer::split`].
+    pub struct StaticStreamBuffer<const N: usize> {
+        trigger_level: usize,
+        memory: StaticHandle<N>,
+        buffer: Once<StreamBuffer>,
+    }
+
+    unsafe impl<const N: usize> Sync for StaticStreamBuffer<N> {}
+
+    impl<const N: usize> StaticStreamBuffer<N> {
+        /// Create the uninitialized memory of a buffer that wakes the reader once
+        /// `trigger_level` bytes are available.
+        ///
+        /// Panics (at compile time in a `static` item) if `trigger_level` is zero or
+        /// larger than `N`.
+        pub const fn new(trigger_level: usize) -> StaticStreamBuffer<N> {
+            assert!(
+                trigger_level > 0 && trigger_level <= N,
+                "the trigger level must be at least 1 and not exceed the buffer size"
+            );
+            StaticStreamBuffer {
+                trigger_level,
+                memory: StaticHandle::new(),
+                buffer: Once::new(),
+            }
+        }
+
+        /// Get the buffer, creating it on the first call.
+        pub fn get(&'static self) -> &'static StreamBuffer {
+            self.buffer.call_once(|| StreamBuffer {
+                handle: self.memory.create(self.trigger_level, false),
+            })
+        }
+
+        /// Split the buffer into its producer and consumer half.
+        ///
+        /// Fails with [`Error::InUse`] if the buffer was already split.
+        pub fn split(
+            &'static self,
+        ) -> Result<(StreamProducer<'static>, StreamConsumer<'static>), Error> {
+            self.memory.take_split()?;
+            Ok(self.get().halves())
+        }
+    }
+
+    /// A [`MessageBuffer`] with `N` bytes of storage that is part of the `static` item
+    /// it is placed in.
+    ///
+    /// The buffer is created on the first call to [`StaticMessageBuffer::get`] or
+    /// [`StaticMessageBuffer::split`].
+    pub struct StaticMessageBuffer<const N: usize> {
+        memory: StaticHandle<N>,
+        buffer: Once<MessageBuffer>,
+    }
+
+    unsafe impl<const N: usize> Sync for StaticMessageBuffer<N> {}
+
+    impl<const N: usize> Default for StaticMessageBuffer<N> {
+        fn default() -> Self {
+            StaticMessageBuffer::new()
+        }
+    }
+
+    impl<const N: usize> StaticMessageBuffer<N> {
+        /// Create the uninitialized buffer memory.
+        ///
+        /// Panics (at compile time in a `static` item) if `N` doesn't leave room for a
+        /// message after the `usize` length prefix.
+        pub const fn new() -> StaticMessageBuffer<N> {
+            assert!(
+                N > mem::size_of::<usize>(),
+                "the buffer must be larger than the message length prefix"
+            );
+            StaticMessageBuffer {
+                memory: StaticHandle::new(),
+                buffer: Once::new(),
+            }
+        }
+
+        /// Get the buffer, creating it on the first call.
+        pub fn get(&'static self) -> &'static MessageBuffer {
+            self.buffer.call_once(|| MessageBuffer {
+                handle: self.memory.create(0, true),
+            })
+        }
+
+        /// Split the buffer into its producer and consumer half.
+        ///
+        /// Fails with [`Error::InUse`] if the buffer was already split.
+        pub fn split(
+            &'static self,
+        ) -> Result<(MessageProducer<'static>, MessageConsumer<'static>), Error> {
+            self.memory.take_split()?;
+            Ok(self.get().halves())
+        }
+    }
+}
+
+#[cfg(all(test, feature = "host-sim", feature = "use-rust-alloc"))]
+mod tests {
+    use core::mem;
+
+    use super::{MessageBuffer, StreamBuffer};
+    use crate::error::Error;
+    use crate::sim;
+    use crate::ticks::Ticks;
+
+    #[test]
+    fn invalid_sizes_are_rejected() {
+        sim::run(|| {
+            let prefix = mem::size_of::<usize>();
+            let invalid = Some(Error::InvalidArgument);
+            assert_eq!(StreamBuffer::new(8, 0).err(), invalid);
+            assert_eq!(StreamBuffer::new(8, 9).err(), invalid);
+            assert!(StreamBuffer::new(8, 8).is_ok());
+            assert_eq!(MessageBuffer::new(prefix).err(), invalid);
+            assert!(MessageBuffer::new(prefix + 1).is_ok());
+        });
+    }
+
+    #[test]
+    fn stream_is_received_in_order() {
+        sim::run(|| {
+            let mut buffer = StreamBuffer::new(8, 1).unwrap();
+            let (producer, consumer) = buffer.split();
+            assert_eq!(producer.send(b"hello world", Ticks::ZERO), 8);
+            assert_eq!(producer.spaces_available(), 0);
+
+            let mut buf = [0; 5];
+            assert_eq!(consumer.receive(&mut buf, Ticks::ZERO), 5);
+            assert_eq!(&buf, b"hello");
+            assert_eq!(consumer.receive(&mut buf, Ticks::ZERO), 3);
+            assert_eq!(&buf[..3], b" wo");
+            assert_eq!(consumer.receive(&mut buf, Ticks::ZERO), 0);
+        });
+    }
+
+    #[test]
+    fn messages_are_received_whole() {
+        sim::run(|| {
+            let mut buffer = MessageBuffer::new(64).unwrap();
+            let (producer, consumer) = buffer.split();
+            assert_eq!(producer.send(b"", Ticks::ZERO), Err(Error::InvalidArgument));
+            producer.send(b"first", Ticks::ZERO).unwrap();
+            producer.send(b"second", Ticks::ZERO).unwrap();
+
+            let mut small = [0; 4];
+            assert_eq!(
+                consumer.receive(&mut small, Ticks::ZERO),
+                Err(Error::BufferTooSmall)
+            );
+            let mut buf = [0; 16];
+            assert_eq!(consumer.receive(&mut buf, Ticks::ZERO), Ok(5));
+            assert_eq!(&buf[..5], b"first");
+            assert_eq!(consumer.next_message_len(), 6);
+            assert_eq!(consumer.receive(&mut buf, Ticks::ZERO), Ok(6));
+            assert_eq!(&buf[..6], b"second");
+            assert_eq!(consumer.receive(&mut buf, Ticks::ZERO), Err(Error::Timeout));
+        });
+    }
+}
@@ -0,0 +1,441 @@
+//! Safe wrappers around FreeRTOS tasks.
+//!
+//! Tasks are spawned with a [`TaskBuilder`] (or the [`spawn`] shorthand) which runs a
+//! Rust closure on a new FreeRTOS task and returns a [`JoinHandle`] to wait for its
+//! result. Spawning requires an allocator (`use-rust-alloc`, `heap-4` or `heap-5`), as
+//! the closure and its result are boxed. With the `static-alloc` feature, tasks running
+//! a plain function can also be spawned into a [`StaticTask`] without using the heap.
+
+use crate::{
+    configMAX_TASK_NAME_LEN, pcTaskGetName, strlen, xTaskGetCurrentTaskHandle, TaskHandle_t,
+    UBaseType_t,
+};
+
+#[cfg(any(feature = "use-rust-alloc", feature = "heap-4", feature = "heap-5"))]
+pub use spawn_impl::*;
+#[cfg(feature = "static-alloc")]
+pub use static_impl::*;
+
+/// A handle to a FreeRTOS task.
+///
+/// The handle doesn't keep the task alive: it dangles once the task was deleted, so the
+/// accessors that read the task control block are `unsafe`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Task(TaskHandle_t);
+
+unsafe impl Send for Task {}
+unsafe impl Sync for Task {}
+
+impl Task {
+    /// Get the task that is currently running on this core.
+    pub fn current() -> Task {
+        Task(unsafe { xTaskGetCurrentTaskHandle() })
+    }
+
+    /// Create a task from a raw FreeRTOS task handle.
+    ///
+    /// # Safety
+    /// `handle` must be a valid task handle and the task must not be deleted while the
+    /// returned [`Task`] is in use.
+    pub unsafe fn from_raw(handle: TaskHandle_t) -> Task {
+        Task(handle)
+    }
+
+    /// Get the raw FreeRTOS task handle.
+    pub fn raw(&self) -> TaskHandle_t {
+        self.0
+    }
+
+    /// Get the name of the task.
+    ///
+    /// # Safety
+    /// The task must not have been deleted, and must not be deleted while the returned
+    /// name is in use.
+    pub unsafe fn name(&self) -> &str {
+        let name = pcTaskGetName(self.0);
+        let len = strlen(name);
+        if len == 0 {
+            ""
+        } else {
+            core::str::from_utf8_unchecked(core::slice::from_raw_parts(
+                name as *const u8,
+                len as usize,
+            ))
+        }
+    }
+}
+
+/// Default stack depth of spawned tasks (in words of `StackType_t`).
+pub const DEFAULT_STACK_DEPTH: u16 = 1024;
+/// Default priority of spawned tasks (one above the idle task).
+pub const DEFAULT_PRIORITY: UBaseType_t = 1;
+
+const NAME_LEN: usize = configMAX_TASK_NAME_LEN as usize;
+
+/// Configuration for a new task.
+#[derive(Debug, Clone)]
+pub struct TaskBuilder {
+    name: [u8; NAME_LEN],
+    stack_depth: u16,
+    priority: UBaseType_t,
+    core_affinity: Option<UBaseType_t>,
+}
+
+impl Default for TaskBuilder {
+    fn default() -> Self {
+        TaskBuilder::new()
+    }
+}
+
+impl TaskBuilder {
+    /// Create a builder with an empty name, [`DEFAULT_STACK_DEPTH`],
+    /// [`DEFAULT_PRIORITY`] and no core affinity.
+    pub const fn new() -> TaskBuilder {
+        TaskBuilder {
+            name: [0; NAME_LEN],
+            stack_depth: DEFAULT_STACK_DEPTH,
+            priority: DEFAULT_PRIORITY,
+            core_affinity: None,
+        }
+    }
+
+    /// Set the name of the task.
+    ///
+    /// Names longer than `configMAX_TASK_NAME_LEN - 1` bytes are truncated.
+    pub fn name(mut self, name: &str) -> TaskBuilder {
+        let len = name.len().min(NAME_LEN - 1);
+        self.name = [0; NAME_LEN];
+        self.name[..len].copy_from_slice(&name.as_bytes()[..len]);
+        self
+    }
+
+    /// Set the stack depth of the task (in words of `StackType_t`).
+    pub fn stack_depth(mut self, stack_depth: u16) -> TaskBuilder {
+        self.stack_depth = stack_depth;
+        self
+    }
+
+    /// Set the priority of the task.
+    pub fn priority(mut self, priority: UBaseType_t) -> TaskBuilder {
+        self.priority = priority;
+        self
+    }
+
+    /// Only allow the task to run on the cores set in `mask` (bit `n` is core `n`).
+    ///
+    /// Ignored on the single core ESP32-S2 and in the `host-sim` mode.
+    pub fn core_affinity(mut self, mask: UBaseType_t) -> TaskBuilder {
+        self.core_affinity = Some(mask);
+        self
+    }
+
+    /// Only allow the task to run on core `core`.
+    pub fn pin_to_core(self, core: usize) -> TaskBuilder {
+        self.core_affinity(1 << core)
+    }
+}
+
+/// Create a task with `create`, which returns its handle or null, and restrict it to
+/// the cores in `core_affinity` before it runs.
+///
+/// The scheduler is suspended until the affinity is set, so that no core can switch
+/// to the new task in between.
+#[cfg(not(any(feature = "esp32s2", feature = "host-sim")))]
+unsafe fn create_with_affinity(
+    core_affinity: Option<UBaseType_t>,
+    create: impl FnOnce() -> TaskHandle_t,
+) -> TaskHandle_t {
+    let mask = match core_affinity {
+        Some(mask) => mask,
+        None => return create(),
+    };
+
+    crate::vTaskSuspendAll();
+    let handle = create();
+    if !handle.is_null() {
+        crate::vTaskCoreAffinitySet(handle, mask);
+    }
+    crate::xTaskResumeAll();
+    handle
+}
+
+/// The kernel has no core affinity on single core chips.
+#[cfg(any(feature = "esp32s2", feature = "host-sim"))]
+unsafe fn create_with_affinity(
+    _core_affinity: Option<UBaseType_t>,
+    create: impl FnOnce() -> TaskHandle_t,
+) -> TaskHandle_t {
+    create()
+}
+
+#[cfg(any(feature = "use-rust-alloc", feature = "heap-4", feature = "heap-5"))]
+mod spawn_impl {
+    use alloc::boxed::Box;
+    use alloc::sync::Arc;
+    use core::cell::UnsafeCell;
+    use core::ptr;
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    use chlorine::{c_char, c_void};
+
+    use super::{create_with_affinity, Task, TaskBuilder};
+    use crate::error::Error;
+    use crate::semaphore::BinarySemaphore;
+    use crate::ticks::Ticks;
+    use crate::{pdPASS, vTaskDelete, xTaskCreate, TaskHandle_t};
+
+    /// State shared between a spawned task and its [`JoinHandle`].
+    struct Packet<T> {
+        /// Written once by the task, before `finished` is set and `done` is given.
+        result: UnsafeCell<Option<T>>,
+        finished: AtomicBool,
+        /// Given once `result` is set.
+        done: BinarySemaphore,
+    }
+
+    // Safe because `result` is only read by `JoinHandle::join`, after the task wrote it.
+    unsafe impl<T: Send> Sync for Packet<T> {}
+
+    /// Everything the task entry point needs, boxed and passed as the task parameter.
+    struct TaskStart<F, T> {
+        f: F,
+        packet: Arc<Packet<T>>,
+    }
+
+    unsafe extern "C" fn task_entry<F, T>(arg: *mut c_void)
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        run_task(Box::from_raw(arg as *mut TaskStart<F, T>));
+
+        // Everything owned by the task was dropped in `run_task`, as `vTaskDelete`
+        // doesn't return.
+        vTaskDelete(ptr::null_mut());
+    }
+
+    fn run_task<F, T>(start: Box<TaskStart<F, T>>)
+    where
+        F: FnOnce() -> T,
+    {
+        let TaskStart { f, packet } = *start;
+
+        let result = f();
+        unsafe { *packet.result.get() = Some(result) };
+        packet.finished.store(true, Ordering::Release);
+        let _ = packet.done.give();
+    }
+
+    impl TaskBuilder {
+        /// Spawn a new task running `f`.
+        ///
+        /// The closure is freed when the task exits, its return value can be retrieved
+        /// with [`JoinHandle::join`].
+        pub fn spawn<F, T>(self, f: F) -> Result<JoinHandle<T>, Error>
+        where
+            F: FnOnce() -> T + Send + 'static,
+            T: Send + 'static,
+        {
+            let packet = Arc::new(Packet {
+                result: UnsafeCell::new(None),
+                finished: AtomicBool::new(false),
+                done: BinarySemaphore::new()?,
+            });
+            let start = Box::into_raw(Box::new(TaskStart {
+                f,
+                packet: packet.clone(),
+            }));
+
+            let handle = unsafe {
+                create_with_affinity(self.core_affinity, || {
+                    let mut handle: TaskHandle_t = ptr::null_mut();
+                    let res = xTaskCreate(
+                        Some(task_entry::<F, T>),
+                        self.name.as_ptr() as *const c_char,
+                        self.stack_depth,
+                        start as *mut c_void,
+                        self.priority,
+                        &mut handle as *mut _,
+                    );
+                    if res == pdPASS {
+                        handle
+                    } else {
+                        ptr::null_mut()
+                    }
+                })
+            };
+
+            if handle.is_null() {
+                // The task was never created, so we still own the closure.
+                drop(unsafe { Box::from_raw(start) });
+                return Err(Error::OutOfMemory);
+            }
+
+            Ok(JoinHandle {
+                task: Task(handle),
+                packet,
+            })
+        }
+    }
+
+    /// Spawn a new task running `f` with the default [`TaskBuilder`] configuration.
+    pub fn spawn<F, T>(f: F) -> Result<JoinHandle<T>, Error>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        TaskBuilder::new().spawn(f)
+    }
+
+    /// An owned permission to wait for a spawned task to finish.
+    ///
+    /// Dropping the handle detaches the task.
+    pub struct JoinHandle<T> {
+        task: Task,
+        packet: Arc<Packet<T>>,
+    }
+
+    impl<T> JoinHandle<T> {
+        /// Get the spawned task.
+        ///
+        /// The task handle becomes dangling once the task finished, see [`Task`].
+        pub fn task(&self) -> Task {
+            self.task
+        }
+
+        /// Whether the task has finished running its closure.
+        pub fn is_finished(&self) -> bool {
+            self.packet.finished.load(Ordering::Acquire)
+        }
+
+        /// Block until the task finished and return the result of its closure.
+        pub fn join(self) -> T {
+            let _ = self.packet.done.take(Ticks::MAX);
+            // Safe because the task gave `done` after writing the result.
+            unsafe { (*self.packet.result.get()).take() }.expect("task finished without a result")
+        }
+    }
+}
+
+#[cfg(feature = "static-alloc")]
+mod static_impl {
+    use core::cell::UnsafeCell;
+    use core::mem::MaybeUninit;
+    use core::ptr;
+    use core::sync::atomic::AtomicBool;
+
+    use chlorine::{c_char, c_void};
+
+    use super::{create_with_affinity, Task, TaskBuilder};
+    use crate::atomic;
+    use crate::error::Error;
+    use crate::{vTaskDelete, xTaskCreateStatic, StackType_t, StaticTask_t};
+
+    /// The memory of a task with a stack of `STACK_DEPTH` words, to be placed in a
+    /// `static` item.
+    ///
+    /// ```ignore
+    /// static WORKER: StaticTask<2048> = StaticTask::new();
+    ///
+    /// TaskBuilder::new().name("worker").spawn_static(&WORKER, worker_main)?;
+    /// ```
+    pub struct StaticTask<const STACK_DEPTH: usize> {
+        tcb: UnsafeCell<MaybeUninit<StaticTask_t>>,
+        stack: UnsafeCell<MaybeUninit<[StackType_t; STACK_DEPTH]>>,
+        /// The function, read by the task once it starts.
+        start: UnsafeCell<Option<fn()>>,
+        spawned: AtomicBool,
+    }
+
+    unsafe impl<const STACK_DEPTH: usize> Sync for StaticTask<STACK_DEPTH> {}
+
+    impl<const STACK_DEPTH: usize> Default for StaticTask<STACK_DEPTH> {
+        fn default() -> Self {
+            StaticTask::new()
+        }
+    }
+
+    impl<const STACK_DEPTH: usize> StaticTask<STACK_DEPTH> {
+        /// Create the uninitialized task memory.
+        pub const fn new() -> StaticTask<STACK_DEPTH> {
+            StaticTask {
+                tcb: UnsafeCell::new(MaybeUninit::uninit()),
+                stack: UnsafeCell::new(MaybeUninit::uninit()),
+                start: UnsafeCell::new(None),
+                spawned: AtomicBool::new(false),
+            }
+        }
+    }
+
+    unsafe extern "C" fn static_task_entry<const STACK_DEPTH: usize>(arg: *mut c_void) {
+        let task = &*(arg as *const StaticTask<STACK_DEPTH>);
+        if let Some(f) = (*task.start.get()).take() {
+            f();
+        }
+
+        // The memory stays reserved, so the task can't be spawned again.
+        vTaskDelete(ptr::null_mut());
+    }
+
+    impl TaskBuilder {
+        /// Spawn a new task running `f` in the memory of `task`.
+        ///
+        /// The stack depth of the builder is ignored, the stack of `task` is used
+        /// instead. Fails with [`Error::InUse`] if `task` was already spawned.
+        pub fn spawn_static<const STACK_DEPTH: usize>(
+            self,
+            task: &'static StaticTask<STACK_DEPTH>,
+            f: fn(),
+        ) -> Result<Task, Error> {
+            if atomic::swap(&task.spawned, true) {
+                return Err(Error::InUse);
+            }
+
+            let handle = unsafe {
+                *task.start.get() = Some(f);
+                // Can't fail, as all memory is provided.
+                create_with_affinity(self.core_affinity, || {
+                    xTaskCreateStatic(
+                        Some(static_task_entry::<STACK_DEPTH>),
+                        self.name.as_ptr() as *const c_char,
+                        STACK_DEPTH as u32,
+                        task as *const _ as *mut c_void,
+                        self.priority,
+                        task.stack.get() as *mut StackType_t,
+                        task.tcb.get() as *mut StaticTask_t,
+                    )
+                })
+            };
+
+            Ok(Task(handle))
+        }
+    }
+}
+
+#[cfg(all(test, feature = "host-sim", feature = "use-rust-alloc"))]
+mod tests {
+    use super::{spawn, Task, TaskBuilder};
+    use crate::sim;
+
+    #[test]
+    fn spawn_and_join() {
+        sim::run(|| {
+            let main = Task::current();
+            let handle = TaskBuilder::new()
+                .name("worker")
+                .spawn(move || {
+                    let current = Task::current();
+                    assert_ne!(current, main);
+                    assert_eq!(unsafe { current.name() }, "worker");
+                    42
+                })
+                .unwrap();
+            assert_ne!(handle.task(), main);
+            assert_eq!(handle.join(), 42);
+
+            let handle = spawn(Task::current).unwrap();
+            let task = handle.task();
+            assert_eq!(handle.join(), task);
+        });
+    }
+}
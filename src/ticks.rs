@@ -0,0 +1,91 @@
+//! Kernel time measured in RTOS ticks.
+
+use core::time::Duration;
+
+use crate::{
+    configTICK_RATE_HZ, portMAX_DELAY, xTaskGetTickCount, xTaskGetTickCountFromISR, TickType_t,
+};
+
+/// A span of time in RTOS ticks, used for timeouts and delays.
+///
+/// One tick is `1 / configTICK_RATE_HZ` seconds long.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Ticks(pub TickType_t);
+
+impl Ticks {
+    /// Don't block at all.
+    pub const ZERO: Ticks = Ticks(0);
+    /// Block indefinitely (`portMAX_DELAY`).
+    pub const MAX: Ticks = Ticks(portMAX_DELAY);
+
+    /// Create a tick count.
+    pub const fn new(ticks: TickType_t) -> Ticks {
+        Ticks(ticks)
+    }
+
+    /// Convert milliseconds to ticks, rounding down (same as `pdMS_TO_TICKS`).
+    pub const fn from_ms(ms: u32) -> Ticks {
+        Ticks(((ms as u64 * configTICK_RATE_HZ as u64) / 1000) as TickType_t)
+    }
+
+    /// Convert the tick count to milliseconds.
+    pub const fn as_ms(&self) -> u64 {
+        (self.0 as u64 * 1000) / configTICK_RATE_HZ as u64
+    }
+
+    /// Get the raw tick count.
+    pub const fn raw(&self) -> TickType_t {
+        self.0
+    }
+
+    /// Whether this is [`Ticks::MAX`], i.e. an infinite timeout.
+    pub const fn is_max(&self) -> bool {
+        self.0 == portMAX_DELAY
+    }
+
+    /// Get the number of ticks since the scheduler started.
+    pub fn now() -> Ticks {
+        Ticks(unsafe { xTaskGetTickCount() })
+    }
+
+    /// Get the number of ticks since the scheduler started, callable from an ISR.
+    pub fn now_from_isr() -> Ticks {
+        Ticks(unsafe { xTaskGetTickCountFromISR() })
+    }
+
+    /// The number of ticks from `self` until the tick count `deadline`, or zero if the
+    /// deadline already passed.
+    ///
+    /// Handles the tick count overflowing, as long as the deadline is less than half
+    /// the tick range away.
+    pub const fn until(&self, deadline: Ticks) -> Ticks {
+        let remaining = deadline.0.wrapping_sub(self.0);
+        // A remaining time in the upper half of the tick range means the deadline passed
+        // and the subtraction wrapped around.
+        if remaining > portMAX_DELAY / 2 {
+            Ticks::ZERO
+        } else {
+            Ticks(remaining)
+        }
+    }
+}
+
+impl From<Duration> for Ticks {
+    /// Convert a duration to ticks, rounding down and saturating at [`Ticks::MAX`].
+    fn from(d: Duration) -> Ticks {
+        let ticks = d.as_millis() * configTICK_RATE_HZ as u128 / 1000;
+        Ticks(ticks.min(portMAX_DELAY as u128) as TickType_t)
+    }
+}
+
+impl From<Ticks> for Duration {
+    fn from(t: Ticks) -> Duration {
+        Duration::from_millis(t.as_ms())
+    }
+}
+
+impl From<TickType_t> for Ticks {
+    fn from(t: TickType_t) -> Ticks {
+        Ticks(t)
+    }
+}
@@ -0,0 +1,422 @@
+//! Software timers with Rust closure callbacks.
+//!
+//! Timer callbacks run in the context of the timer daemon task, so they must not
+//! block. All timer commands are sent to the daemon through its command queue, the
+//! `timeout` arguments specify how long to wait for space in that queue.
+
+use alloc::boxed::Box;
+use core::ptr;
+use core::time::Duration;
+
+use chlorine::{c_char, c_void};
+
+use crate::daemon::timer_queue_timeout;
+use crate::error::Error;
+use crate::ticks::Ticks;
+use crate::{
+    configMAX_TASK_NAME_LEN, pdFALSE, pdPASS, pvTimerGetTimerID, tmrCOMMAND_CHANGE_PERIOD,
+    tmrCOMMAND_CHANGE_PERIOD_FROM_ISR, tmrCOMMAND_DELETE, tmrCOMMAND_RESET,
+    tmrCOMMAND_RESET_FROM_ISR, tmrCOMMAND_START, tmrCOMMAND_START_FROM_ISR, tmrCOMMAND_STOP,
+    tmrCOMMAND_STOP_FROM_ISR, vTimerSetReloadMode, xTimerCreate, xTimerGenericCommandFromISR,
+    xTimerGenericCommandFromTask, xTimerGetExpiryTime, xTimerGetPeriod, xTimerIsTimerActive,
+    xTimerPendFunctionCall, BaseType_t, TimerHandle_t, UBaseType_t,
+};
+
+#[cfg(feature = "static-alloc")]
+pub use static_impl::*;
+
+const NAME_LEN: usize = configMAX_TASK_NAME_LEN as usize;
+
+/// The state owned by a timer, its address is the timer ID.
+struct TimerInner {
+    /// Nul-terminated name, the kernel only stores a pointer to it.
+    name: [u8; NAME_LEN],
+    callback: Box<dyn FnMut() + Send>,
+}
+
+unsafe extern "C" fn timer_callback(handle: TimerHandle_t) {
+    let inner = pvTimerGetTimerID(handle) as *mut TimerInner;
+    ((*inner).callback)();
+}
+
+unsafe extern "C" fn drop_timer_inner(inner: *mut c_void, _: u32) {
+    drop(Box::from_raw(inner as *mut TimerInner));
+}
+
+/// A FreeRTOS software timer that calls a closure when it expires.
+///
+/// The `*_from_isr` variants never block and additionally return whether a higher
+/// priority task (i.e. the timer daemon) was woken, in which case the ISR should yield
+/// (see [`vPortYieldFromISR`]) before it returns.
+///
+/// Dropping a timer from the timer daemon (e.g. in a callback) or while the scheduler
+/// is suspended doesn't wait for space in the command queue. If it is full, the
+/// closure is leaked rather than freed while the timer may still call it.
+///
+/// [`vPortYieldFromISR`]: crate::vPortYieldFromISR
+pub struct Timer {
+    handle: TimerHandle_t,
+    /// Null for the timers of [`StaticTimer`]s, which are never dropped.
+    inner: *mut TimerInner,
+}
+
+unsafe impl Send for Timer {}
+unsafe impl Sync for Timer {}
+
+impl Timer {
+    /// Create a new, dormant timer that calls `callback` `period` ticks after it was
+    /// started.
+    ///
+    /// Auto-reload timers restart themselves after they expired, one-shot timers must
+    /// be started again manually. Names longer than `configMAX_TASK_NAME_LEN - 1` bytes
+    /// are truncated. Fails with [`Error::InvalidArgument`] if `period` is zero, which
+    /// the kernel rejects.
+    pub fn new<F>(name: &str, period: Ticks, auto_reload: bool, callback: F) -> Result<Timer, Error>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        if period == Ticks::ZERO {
+            return Err(Error::InvalidArgument);
+        }
+
+        let mut inner = Box::new(TimerInner {
+            name: [0; NAME_LEN],
+            callback: Box::new(callback),
+        });
+        let len = name.len().min(NAME_LEN - 1);
+        inner.name[..len].copy_from_slice(&name.as_bytes()[..len]);
+
+        let inner = Box::into_raw(inner);
+        let handle = unsafe {
+            xTimerCreate(
+                (*inner).name.as_ptr() as *const c_char,
+                period.raw(),
+                auto_reload as UBaseType_t,
+                inner as *mut c_void,
+                Some(timer_callback),
+            )
+        };
+        if handle.is_null() {
+            drop(unsafe { Box::from_raw(inner) });
+            return Err(Error::OutOfMemory);
+        }
+
+        Ok(Timer { handle, inner })
+    }
+
+    /// Create a one-shot timer.
+    pub fn one_shot<F>(name: &str, period: Ticks, callback: F) -> Result<Timer, Error>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        Timer::new(name, period, false, callback)
+    }
+
+    /// Create an auto-reload timer.
+    pub fn auto_reload<F>(name: &str, period: Ticks, callback: F) -> Result<Timer, Error>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        Timer::new(name, period, true, callback)
+    }
+
+    /// Get the raw FreeRTOS timer handle.
+    pub fn raw(&self) -> TimerHandle_t {
+        self.handle
+    }
+
+    /// Start the timer, or restart it if it is already running.
+    pub fn start(&self, timeout: Ticks) -> Result<(), Error> {
+        self.command(tmrCOMMAND_START, Ticks::now(), timeout)
+    }
+
+    /// Stop the timer.
+    pub fn stop(&self, timeout: Ticks) -> Result<(), Error> {
+        self.command(tmrCOMMAND_STOP, Ticks::ZERO, timeout)
+    }
+
+    /// Restart the timer, so that it expires `period` ticks from now.
+    pub fn reset(&self, timeout: Ticks) -> Result<(), Error> {
+        self.command(tmrCOMMAND_RESET, Ticks::now(), timeout)
+    }
+
+    /// Change the period of the timer and start it.
+    ///
+    /// Fails with [`Error::InvalidArgument`] if `period` is zero.
+    pub fn change_period(&self, period: Ticks, timeout: Ticks) -> Result<(), Error> {
+        if period == Ticks::ZERO {
+            return Err(Error::InvalidArgument);
+        }
+
+        self.command(tmrCOMMAND_CHANGE_PERIOD, period, timeout)
+    }
+
+    /// Start the timer from an ISR.
+    pub fn start_from_isr(&self) -> Result<bool, Error> {
+        self.command_from_isr(tmrCOMMAND_START_FROM_ISR, Ticks::now_from_isr())
+    }
+
+    /// Stop the timer from an ISR.
+    pub fn stop_from_isr(&self) -> Result<bool, Error> {
+        self.command_from_isr(tmrCOMMAND_STOP_FROM_ISR, Ticks::ZERO)
+    }
+
+    /// Restart the timer from an ISR.
+    pub fn reset_from_isr(&self) -> Result<bool, Error> {
+        self.command_from_isr(tmrCOMMAND_RESET_FROM_ISR, Ticks::now_from_isr())
+    }
+
+    /// Change the period of the timer and start it from an ISR.
+    ///
+    /// Fails with [`Error::InvalidArgument`] if `period` is zero.
+    pub fn change_period_from_isr(&self, period: Ticks) -> Result<bool, Error> {
+        if period == Ticks::ZERO {
+            return Err(Error::InvalidArgument);
+        }
+
+        self.command_from_isr(tmrCOMMAND_CHANGE_PERIOD_FROM_ISR, period)
+    }
+
+    /// Switch between auto-reload (`true`) and one-shot (`false`) mode.
+    pub fn set_auto_reload(&self, auto_reload: bool) {
+        unsafe { vTimerSetReloadMode(self.handle, auto_reload as UBaseType_t) }
+    }
+
+    /// Whether the timer is running.
+    pub fn is_active(&self) -> bool {
+        unsafe { xTimerIsTimerActive(self.handle) != pdFALSE }
+    }
+
+    /// The period of the timer.
+    pub fn period(&self) -> Ticks {
+        Ticks(unsafe { xTimerGetPeriod(self.handle) })
+    }
+
+    /// The tick count at which the timer expires.
+    ///
+    /// Only meaningful if the timer [is active](Timer::is_active).
+    pub fn expiry_time(&self) -> Ticks {
+        Ticks(unsafe { xTimerGetExpiryTime(self.handle) })
+    }
+
+    /// The time until the timer expires, or zero if it already expired.
+    ///
+    /// Only meaningful if the timer [is active](Timer::is_active).
+    pub fn remaining(&self) -> Duration {
+        Ticks::now().until(self.expiry_time()).into()
+    }
+
+    fn command(&self, command: BaseType_t, value: Ticks, timeout: Ticks) -> Result<(), Error> {
+        let res = unsafe {
+            xTimerGenericCommandFromTask(
+                self.handle,
+                command,
+                value.raw(),
+                ptr::null_mut(),
+                timeout.raw(),
+            )
+        };
+
+        if res == pdPASS {
+            Ok(())
+        } else {
+            Err(Error::Timeout)
+        }
+    }
+
+    fn command_from_isr(&self, command: BaseType_t, value: Ticks) -> Result<bool, Error> {
+        let mut woken: BaseType_t = pdFALSE;
+        let res = unsafe {
+            xTimerGenericCommandFromISR(self.handle, command, value.raw(), &mut woken as *mut _, 0)
+        };
+
+        if res == pdPASS {
+            Ok(woken != pdFALSE)
+        } else {
+            Err(Error::QueueFull)
+        }
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        // The callback may still run until the daemon processed the delete command, so
+        // the closure is dropped from a function that is queued after it. The daemon
+        // can't block on its own queue, that would deadlock.
+        let timeout = timer_queue_timeout().unwrap_or(Ticks::ZERO);
+        let deleted = self.command(tmrCOMMAND_DELETE, Ticks::ZERO, timeout);
+        if deleted.is_ok() {
+            unsafe {
+                xTimerPendFunctionCall(
+                    Some(drop_timer_inner),
+                    self.inner as *mut c_void,
+                    0,
+                    timeout.raw(),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(feature = "static-alloc")]
+mod static_impl {
+    use core::cell::UnsafeCell;
+    use core::mem::MaybeUninit;
+    use core::ptr;
+
+    use chlorine::{c_char, c_void};
+
+    use super::{Timer, NAME_LEN};
+    use crate::atomic::Once;
+    use crate::ticks::Ticks;
+    use crate::{pvTimerGetTimerID, xTimerCreateStatic, StaticTimer_t, TimerHandle_t, UBaseType_t};
+
+    /// A [`Timer`] calling a plain function, whose memory is part of the `static` item it
+    /// is placed in.
+    ///
+    /// The timer is created on the first call to [`StaticTimer::get`].
+    ///
+    /// ```ignore
+    /// static BLINK: StaticTimer = StaticTimer::new("blink", Ticks::from_ms(500), true, toggle_led);
+    ///
+    /// BLINK.get().start(Ticks::MAX)?;
+    /// ```
+    pub struct StaticTimer {
+        name: &'static str,
+        period: Ticks,
+        auto_reload: bool,
+        callback: fn(),
+        /// Nul-terminated copy of `name`, the kernel only stores a pointer to it.
+        name_buffer: UnsafeCell<[u8; NAME_LEN]>,
+        buffer: UnsafeCell<MaybeUninit<StaticTimer_t>>,
+        timer: Once<Timer>,
+    }
+
+    unsafe impl Sync for StaticTimer {}
+
+    unsafe extern "C" fn static_timer_callback(handle: TimerHandle_t) {
+        let timer = &*(pvTimerGetTimerID(handle) as *const StaticTimer);
+        (timer.callback)();
+    }
+
+    impl StaticTimer {
+        /// Create the uninitialized memory of a timer that calls `callback` `period`
+        /// ticks after it was started.
+        ///
+        /// Names longer than `configMAX_TASK_NAME_LEN - 1` bytes are truncated. Panics
+        /// (at compile time in a `static` item) if `period` is zero, which the kernel
+        /// rejects.
+        pub const fn new(
+            name: &'static str,
+            period: Ticks,
+            auto_reload: bool,
+            callback: fn(),
+        ) -> StaticTimer {
+            assert!(period.raw() > 0, "the timer period must not be zero");
+            StaticTimer {
+                name,
+                period,
+                auto_reload,
+                callback,
+                name_buffer: UnsafeCell::new([0; NAME_LEN]),
+                buffer: UnsafeCell::new(MaybeUninit::uninit()),
+                timer: Once::new(),
+            }
+        }
+
+        /// Get the dormant timer, creating it on the first call.
+        pub fn get(&'static self) -> &'static Timer {
+            self.timer.call_once(|| {
+                let name = unsafe { &mut *self.name_buffer.get() };
+                let len = self.name.len().min(NAME_LEN - 1);
+                name[..len].copy_from_slice(&self.name.as_bytes()[..len]);
+
+                // Can't fail, as all memory is provided and `new` checked the period.
+                let handle = unsafe {
+                    xTimerCreateStatic(
+                        name.as_ptr() as *const c_char,
+                        self.period.raw(),
+                        self.auto_reload as UBaseType_t,
+                        self as *const _ as *mut c_void,
+                        Some(static_timer_callback),
+                        self.buffer.get() as *mut StaticTimer_t,
+                    )
+                };
+                Timer {
+                    handle,
+                    inner: ptr::null_mut(),
+                }
+            })
+        }
+    }
+}
+
+#[cfg(all(test, feature = "host-sim"))]
+mod tests {
+    extern crate std;
+    use std::sync::Arc;
+
+    use super::Timer;
+    use crate::error::Error;
+    use crate::semaphore::CountingSemaphore;
+    use crate::sim;
+    use crate::ticks::Ticks;
+    use crate::vTaskDelay;
+
+    #[test]
+    fn one_shot() {
+        sim::run(|| {
+            let fired = Arc::new(CountingSemaphore::new(10, 0).unwrap());
+            let give = fired.clone();
+            let timer = Timer::one_shot("once", Ticks::new(2), move || {
+                give.give().unwrap();
+            })
+            .unwrap();
+            assert!(!timer.is_active());
+            assert_eq!(timer.period(), Ticks::new(2));
+
+            timer.start(Ticks::MAX).unwrap();
+            fired.take(Ticks::new(100)).unwrap();
+            assert!(!timer.is_active());
+            assert!(fired.take(Ticks::new(5)).is_err());
+        });
+    }
+
+    #[test]
+    fn auto_reload_until_stopped() {
+        sim::run(|| {
+            let fired = Arc::new(CountingSemaphore::new(100, 0).unwrap());
+            let give = fired.clone();
+            let timer = Timer::auto_reload("reload", Ticks::new(1), move || {
+                let _ = give.give();
+            })
+            .unwrap();
+
+            timer.start(Ticks::MAX).unwrap();
+            for _ in 0..3 {
+                fired.take(Ticks::new(100)).unwrap();
+            }
+            assert!(timer.is_active());
+
+            // The daemon has the priority of the test, let it process the command.
+            timer.stop(Ticks::MAX).unwrap();
+            unsafe { vTaskDelay(2) };
+            assert!(!timer.is_active());
+            while fired.take(Ticks::ZERO).is_ok() {}
+            assert!(fired.take(Ticks::new(5)).is_err());
+        });
+    }
+
+    #[test]
+    fn zero_period_is_rejected() {
+        sim::run(|| {
+            let invalid = Some(Error::InvalidArgument);
+            assert_eq!(Timer::one_shot("zero", Ticks::ZERO, || {}).err(), invalid);
+
+            let timer = Timer::one_shot("period", Ticks::new(5), || {}).unwrap();
+            assert_eq!(timer.change_period(Ticks::ZERO, Ticks::MAX).err(), invalid);
+            assert_eq!(timer.period(), Ticks::new(5));
+            assert!(!timer.is_active());
+        });
+    }
+}
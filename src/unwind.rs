@@ -0,0 +1,276 @@
+//! The target independent core of the backtraces: unwinding a stack of the Xtensa
+//! windowed ABI through a [`StackMemory`].
+//!
+//! The `backtrace` module unwinds the running chip with it, but the unwinder itself
+//! doesn't touch the hardware, so it also builds (and is tested) on the host and can
+//! walk stack images, e.g. from a core dump.
+
+use core::fmt;
+
+/// The default maximum number of frames of an [`Unwinder`].
+pub const DEFAULT_MAX_DEPTH: usize = 64;
+
+/// A frame in the backtrace
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BacktraceFrame {
+    /// The address of the last instruction in the backtrace (program counter).
+    pub pc: u32,
+    /// The address of the current stack frame in the backtrace (stack pointer).
+    pub sp: u32,
+    /// Set on the last frame if the backtrace ended before the bottom of the stack.
+    pub truncated: Option<Truncation>,
+}
+
+/// Why a backtrace ended before the bottom of the stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Truncation {
+    /// The PC or SP of the frame is invalid, the stack is corrupted.
+    Corrupted,
+    /// The SP of the caller isn't above the SP of the frame, following it would loop.
+    Loop,
+    /// The maximum depth of the backtrace was reached.
+    MaxDepth,
+}
+
+impl fmt::Display for BacktraceFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#08x}:{:#08x}", self.pc, self.sp)?;
+        match self.truncated {
+            Some(Truncation::Corrupted) => write!(f, " |<-CORRUPTED"),
+            Some(Truncation::Loop) => write!(f, " |<-LOOP"),
+            Some(Truncation::MaxDepth) => write!(f, " |<-CONTINUES"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Read access to the stack that is unwound by an [`Unwinder`].
+///
+/// # Safety
+/// [`StackMemory::read_u32`] must be safe to call for the 16 bytes below the stack
+/// pointer of every frame that [`StackMemory::is_sane`] accepts, as the unwinder reads
+/// the base save area of the caller there.
+pub unsafe trait StackMemory {
+    /// Whether the PC and SP of `frame` are plausible for this memory.
+    fn is_sane(&self, frame: &BacktraceFrame) -> bool;
+
+    /// Read the word at `addr`, which is 4 byte aligned.
+    ///
+    /// # Safety
+    /// `addr` must be readable, i.e. in the base save area of a frame that
+    /// [`StackMemory::is_sane`] accepted.
+    unsafe fn read_u32(&self, addr: u32) -> u32;
+}
+
+/// A backtrace iterator that returns [`BacktraceFrame`]s.
+///
+/// The backtrace ends at the bottom of the stack (a zero return address), or with a
+/// [`Truncation`] on the last frame if the stack is corrupted, loops or is deeper than
+/// the maximum depth.
+#[derive(Debug, Clone)]
+pub struct Unwinder<M: StackMemory> {
+    pc: u32,
+    sp: u32,
+    next_pc: u32,
+    depth: usize,
+    max_depth: usize,
+    last: bool,
+    memory: M,
+}
+
+impl<M: StackMemory> Unwinder<M> {
+    /// Start a backtrace at the frame with `pc` and `sp`, whose caller continues at
+    /// `next_pc`, reading the stack from `memory`.
+    pub fn with_memory(memory: M, pc: u32, sp: u32, next_pc: u32) -> Unwinder<M> {
+        Unwinder {
+            pc,
+            sp,
+            next_pc,
+            depth: 0,
+            max_depth: DEFAULT_MAX_DEPTH,
+            last: false,
+            memory,
+        }
+    }
+
+    /// Set the maximum number of frames, [`DEFAULT_MAX_DEPTH`] by default.
+    pub fn max_depth(mut self, max_depth: usize) -> Unwinder<M> {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Convert the PC register value to its true address
+    ///
+    /// The address of the current instruction is not stored as an exact u32
+    /// representation in PC register. This function will convert the value stored in the
+    /// PC register to a u32 address.
+    ///
+    /// Ported from `esp-idf/components/soc/include/soc/cpu.h`
+    pub fn get_real_pc(&self) -> u32 {
+        let mut pc = self.pc;
+        if (pc & 0x80000000) > 0 {
+            pc = (pc & 0x3fffffff) | 0x40000000;
+        }
+        pc.saturating_sub(3)
+    }
+
+    /// Get the previous stack frame from the current stack pointer
+    ///
+    /// The current frame must be sane, see [`StackMemory::is_sane`].
+    fn next_stack_frame(&mut self) {
+        // Use frame(i-1)'s BS area located below frame(i)'s sp to get frame(i-1)'s sp and frame(i-2)'s pc
+        let base_save = self.sp; // Base save area consists of 4 words under SP
+        self.pc = self.next_pc;
+        unsafe {
+            self.next_pc = self.memory.read_u32(base_save - 16); //If next_pc = 0, indicates frame(i-1) is the last frame on the stack
+            self.sp = self.memory.read_u32(base_save - 12);
+        }
+    }
+}
+
+impl<M: StackMemory> Iterator for Unwinder<M> {
+    type Item = BacktraceFrame;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.last {
+            return None;
+        }
+
+        let mut res = BacktraceFrame {
+            pc: self.get_real_pc(),
+            sp: self.sp,
+            truncated: None,
+        };
+        self.depth += 1;
+        self.last = true;
+
+        if !self.memory.is_sane(&res) {
+            res.truncated = Some(Truncation::Corrupted);
+        } else if self.next_pc == 0 {
+            // The bottom of the stack.
+        } else if self.depth >= self.max_depth {
+            res.truncated = Some(Truncation::MaxDepth);
+        } else {
+            self.next_stack_frame();
+            // The stack grows down, so every caller has a higher stack pointer.
+            if self.sp <= res.sp {
+                res.truncated = Some(Truncation::Loop);
+            } else {
+                self.last = false;
+            }
+        }
+
+        Some(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BacktraceFrame, StackMemory, Truncation, Unwinder};
+
+    extern crate std;
+    use std::collections::BTreeMap;
+    use std::vec::Vec;
+
+    /// A synthetic stack image, with addresses of the ESP32 memory map.
+    struct Image(BTreeMap<u32, u32>);
+
+    unsafe impl StackMemory for Image {
+        fn is_sane(&self, frame: &BacktraceFrame) -> bool {
+            (0x3ffa_e010..0x4000_0000).contains(&frame.sp)
+                && frame.sp & 0xf == 0
+                && (0x4000_0000..0x4040_0000).contains(&frame.pc)
+        }
+
+        unsafe fn read_u32(&self, addr: u32) -> u32 {
+            *self.0.get(&addr).expect("read outside of the stack image")
+        }
+    }
+
+    /// Build the base save areas of `frames` (windowed return address, stack pointer)
+    /// and start a backtrace at the first one.
+    fn backtrace(frames: &[(u32, u32)]) -> Unwinder<Image> {
+        let pc = |i: usize| frames.get(i).map_or(0, |f| f.0);
+        let mut image = BTreeMap::new();
+        for (i, &(_, sp)) in frames.iter().enumerate() {
+            image.insert(sp - 16, pc(i + 2));
+            image.insert(sp - 12, frames.get(i + 1).map_or(0, |f| f.1));
+        }
+
+        Unwinder::with_memory(Image(image), frames[0].0, frames[0].1, pc(1))
+    }
+
+    fn frame(pc: u32, sp: u32, truncated: Option<Truncation>) -> BacktraceFrame {
+        BacktraceFrame { pc, sp, truncated }
+    }
+
+    #[test]
+    fn walks_to_the_bottom_of_the_stack() {
+        let frames: Vec<_> = backtrace(&[
+            (0x800d_1000, 0x3ffb_0000),
+            (0x800d_2000, 0x3ffb_0040),
+            (0x4008_1000, 0x3ffb_0080),
+        ])
+        .collect();
+
+        assert_eq!(
+            frames,
+            [
+                frame(0x400d_0ffd, 0x3ffb_0000, None),
+                frame(0x400d_1ffd, 0x3ffb_0040, None),
+                frame(0x4008_0ffd, 0x3ffb_0080, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn stops_at_a_corrupted_frame() {
+        let invalid_pc: Vec<_> = backtrace(&[
+            (0x800d_1000, 0x3ffb_0000),
+            (0x0000_1234, 0x3ffb_0040),
+            (0x800d_3000, 0x3ffb_0080),
+        ])
+        .collect();
+        assert_eq!(invalid_pc.len(), 2);
+        assert_eq!(invalid_pc[1].truncated, Some(Truncation::Corrupted));
+
+        let invalid_sp: Vec<_> = backtrace(&[
+            (0x800d_1000, 0x3ffb_0000),
+            (0x800d_2000, 0x3ffb_0044),
+            (0x800d_3000, 0x3ffb_0080),
+        ])
+        .collect();
+        assert_eq!(invalid_sp.len(), 2);
+        assert_eq!(invalid_sp[1].truncated, Some(Truncation::Corrupted));
+    }
+
+    #[test]
+    fn detects_loops() {
+        // The caller of the second frame is the first frame again.
+        let mut image = BTreeMap::new();
+        image.insert(0x3ffb_0040 - 16, 0x800d_1000);
+        image.insert(0x3ffb_0040 - 12, 0x3ffb_0080);
+        image.insert(0x3ffb_0080 - 16, 0x800d_2000);
+        image.insert(0x3ffb_0080 - 12, 0x3ffb_0040);
+
+        let frames: Vec<_> =
+            Unwinder::with_memory(Image(image), 0x800d_1000, 0x3ffb_0040, 0x800d_2000).collect();
+
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].truncated, None);
+        assert_eq!(frames[1].truncated, Some(Truncation::Loop));
+    }
+
+    #[test]
+    fn stops_at_the_max_depth() {
+        let stack: Vec<_> = (0..8)
+            .map(|i| (0x800d_1000 + i * 0x100, 0x3ffb_0000 + i * 0x40))
+            .collect();
+
+        let frames: Vec<_> = backtrace(&stack).max_depth(3).collect();
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[2].truncated, Some(Truncation::MaxDepth));
+
+        assert_eq!(backtrace(&stack).count(), 8);
+    }
+}
@@ -0,0 +1,182 @@
+//! Symbolize the backtraces printed by `freertos-esp32-sys`.
+//!
+//! Usage: `freertos-esp32-symbolizer <firmware.elf> [log]`
+//!
+//! Copies the log (or stdin) to stdout and prints the function, file and line of every
+//! `pc:sp` backtrace frame below the line it was found in, including inlined functions.
+
+use std::borrow::Cow;
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+
+use addr2line::gimli::{EndianRcSlice, RunTimeEndian};
+use addr2line::object::{self, Object, ObjectSymbol};
+use addr2line::Context;
+use anyhow::{bail, Context as _, Result};
+
+fn main() -> Result<()> {
+    let args: Vec<_> = env::args().skip(1).collect();
+    let (elf, log) = match args.as_slice() {
+        [elf] => (elf, None),
+        [elf, log] => (elf, Some(log)),
+        _ => bail!("usage: freertos-esp32-symbolizer <firmware.elf> [log]"),
+    };
+
+    let data = fs::read(elf).with_context(|| format!("failed to read {}", elf))?;
+    let file = object::File::parse(&*data).context("failed to parse the firmware ELF")?;
+    let symbolizer = Symbolizer::new(&file)?;
+
+    let input: Box<dyn BufRead> = match log {
+        Some(log) => Box::new(BufReader::new(
+            fs::File::open(log).with_context(|| format!("failed to open {}", log))?,
+        )),
+        None => Box::new(BufReader::new(io::stdin())),
+    };
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+
+    for line in input.lines() {
+        let line = line?;
+        writeln!(out, "{}", line)?;
+        for (pc, _sp) in parse_frames(&line) {
+            symbolizer.write_frame(&mut out, real_pc(pc))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Looks up addresses in the DWARF info of the firmware, or in its symbol table if it
+/// was built without debug info.
+struct Symbolizer<'data> {
+    context: Context<EndianRcSlice<RunTimeEndian>>,
+    symbols: Vec<(u64, u64, &'data str)>,
+}
+
+impl<'data> Symbolizer<'data> {
+    fn new(file: &object::File<'data>) -> Result<Symbolizer<'data>> {
+        let context = Context::new(file).context("failed to read the DWARF info")?;
+        let mut symbols: Vec<_> = file
+            .symbols()
+            .filter(|s| s.kind() == object::SymbolKind::Text && s.size() > 0)
+            .filter_map(|s| Some((s.address(), s.size(), s.name().ok()?)))
+            .collect();
+        symbols.sort_unstable();
+
+        Ok(Symbolizer { context, symbols })
+    }
+
+    /// Write the functions at `pc`, the innermost inlined function first, in the style
+    /// of `addr2line -pfiaC`.
+    fn write_frame(&self, out: &mut impl Write, pc: u32) -> Result<()> {
+        let mut frames = self.context.find_frames(pc as u64)?;
+        let mut first = true;
+
+        while let Some(frame) = frames.next()? {
+            let function = match &frame.function {
+                Some(function) => function.demangle()?,
+                None => Cow::from("??"),
+            };
+            let (file, line) = match &frame.location {
+                Some(location) => (location.file.unwrap_or("??"), location.line.unwrap_or(0)),
+                None => ("??", 0),
+            };
+
+            if first {
+                write!(out, "    {:#010x}: ", pc)?;
+            } else {
+                write!(out, "     (inlined by) ")?;
+            }
+            writeln!(out, "{} at {}:{}", function, file, line)?;
+            first = false;
+        }
+
+        if first {
+            let function = self
+                .symbol(pc as u64)
+                .map(|name| addr2line::demangle_auto(Cow::from(name), None))
+                .unwrap_or(Cow::from("??"));
+            writeln!(out, "    {:#010x}: {} at ??:0", pc, function)?;
+        }
+
+        Ok(())
+    }
+
+    /// The name of the function symbol containing `addr`.
+    fn symbol(&self, addr: u64) -> Option<&'data str> {
+        let i = match self.symbols.binary_search_by_key(&addr, |s| s.0) {
+            Ok(i) => i,
+            Err(0) => return None,
+            Err(i) => i - 1,
+        };
+        let (start, size, name) = self.symbols[i];
+        if addr < start + size {
+            Some(name)
+        } else {
+            None
+        }
+    }
+}
+
+/// Convert a windowed return address (e.g. A0 in a register dump) to the address of the
+/// call instruction, like `Backtrace::get_real_pc` does on the target.
+///
+/// Frames printed by `Backtrace` are already converted and returned unchanged.
+fn real_pc(pc: u32) -> u32 {
+    if pc & 0x8000_0000 != 0 {
+        ((pc & 0x3fff_ffff) | 0x4000_0000).saturating_sub(3)
+    } else {
+        pc
+    }
+}
+
+/// Find the `pc:sp` pairs printed by `BacktraceFrame` in `line`.
+fn parse_frames(line: &str) -> Vec<(u32, u32)> {
+    let parse = |s: &str| {
+        let hex = s.strip_prefix("0x")?;
+        u32::from_str_radix(hex, 16).ok()
+    };
+
+    let mut frames = Vec::new();
+    for word in line.split_whitespace() {
+        // Also matches frames glued to a prefix, e.g. `Backtrace:0x400d0ffd:0x3ffb0000`.
+        let parts: Vec<_> = word.split(':').collect();
+        let mut i = 0;
+        while i + 1 < parts.len() {
+            match (parse(parts[i]), parse(parts[i + 1])) {
+                (Some(pc), Some(sp)) => {
+                    frames.push((pc, sp));
+                    i += 2;
+                }
+                _ => i += 1,
+            }
+        }
+    }
+
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_frames, real_pc};
+
+    #[test]
+    fn parses_backtrace_lines() {
+        assert_eq!(
+            parse_frames("Backtrace:0x400d0ffd:0x3ffb0000 0x400d1ffd:0x3ffb0040 |<-CORRUPTED"),
+            [(0x400d_0ffd, 0x3ffb_0000), (0x400d_1ffd, 0x3ffb_0040)]
+        );
+        assert_eq!(
+            parse_frames("main (ready, stack high water mark 12): 0x400d0ffd:0x3ffb0000"),
+            [(0x400d_0ffd, 0x3ffb_0000)]
+        );
+        assert!(parse_frames("PC      : 0x400d0ffd  PS      : 0x00060030").is_empty());
+    }
+
+    #[test]
+    fn converts_windowed_return_addresses() {
+        assert_eq!(real_pc(0x800d_1000), 0x400d_0ffd);
+        assert_eq!(real_pc(0x400d_0ffd), 0x400d_0ffd);
+    }
+}
@@ -6,6 +6,10 @@
 #include <queue.h>
 #include <atomic.h>
 #include <event_groups.h>
+#include <stream_buffer.h>
+#include <message_buffer.h>
 #include <portable.h>
+#ifdef __XTENSA__
 #include <xtensa_context.h>
-#include <xtensa_api.h>
\ No newline at end of file
+#include <xtensa_api.h>
+#endif